@@ -0,0 +1,535 @@
+use crate::{Game, LevelID};
+
+const NO_GAME: Game = Game::None;
+const SONIC_1: Game = Game::Sonic1;
+const SONIC_CD: Game = Game::SonicCD;
+const SONIC_2: Game = Game::Sonic2;
+const SONIC_3: Game = Game::Sonic3;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum LevelKind {
+    /// Menus, title screens and anything that couldn't be mapped.
+    Other,
+    Act,
+    Ending,
+}
+
+/// Static description of a `LevelID`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct LevelInfo {
+    pub game: Game,
+    pub kind: LevelKind,
+    /// Zone the level belongs to. `None` for menus, title screens and endings.
+    pub zone: Option<&'static str>,
+    /// Act number within the zone. `None` for anything that is not a playable act.
+    pub act: Option<u8>,
+    /// Whether the act ends with the zone's main boss.
+    pub boss: bool,
+    pub name: &'static str,
+}
+
+impl LevelInfo {
+    const fn other(game: Game, name: &'static str) -> Self {
+        Self {
+            game,
+            kind: LevelKind::Other,
+            zone: None,
+            act: None,
+            boss: false,
+            name,
+        }
+    }
+
+    const fn ending(game: Game) -> Self {
+        Self {
+            kind: LevelKind::Ending,
+            ..Self::other(game, "Ending")
+        }
+    }
+
+    const fn act(game: Game, zone: &'static str, act: u8, name: &'static str) -> Self {
+        Self {
+            kind: LevelKind::Act,
+            zone: Some(zone),
+            act: Some(act),
+            ..Self::other(game, name)
+        }
+    }
+
+    const fn boss(game: Game, zone: &'static str, act: u8, name: &'static str) -> Self {
+        Self {
+            boss: true,
+            ..Self::act(game, zone, act, name)
+        }
+    }
+}
+
+impl LevelID {
+    pub const fn info(self) -> LevelInfo {
+        match self {
+            LevelID::MainMenu => LevelInfo::other(NO_GAME, "Main Menu"),
+            LevelID::Unknown => LevelInfo::other(NO_GAME, "Unknown"),
+            LevelID::Sonic1_TitleScreen => LevelInfo::other(SONIC_1, "Title Screen"),
+            LevelID::Sonic1_GreenHillAct1 => {
+                LevelInfo::act(SONIC_1, "Green Hill Zone", 1, "Green Hill Zone - Act 1")
+            }
+            LevelID::Sonic1_GreenHillAct2 => {
+                LevelInfo::act(SONIC_1, "Green Hill Zone", 2, "Green Hill Zone - Act 2")
+            }
+            LevelID::Sonic1_GreenHillAct3 => {
+                LevelInfo::boss(SONIC_1, "Green Hill Zone", 3, "Green Hill Zone - Act 3")
+            }
+            LevelID::Sonic1_MarbleAct1 => {
+                LevelInfo::act(SONIC_1, "Marble Zone", 1, "Marble Zone - Act 1")
+            }
+            LevelID::Sonic1_MarbleAct2 => {
+                LevelInfo::act(SONIC_1, "Marble Zone", 2, "Marble Zone - Act 2")
+            }
+            LevelID::Sonic1_MarbleAct3 => {
+                LevelInfo::boss(SONIC_1, "Marble Zone", 3, "Marble Zone - Act 3")
+            }
+            LevelID::Sonic1_SpringYardAct1 => {
+                LevelInfo::act(SONIC_1, "Spring Yard Zone", 1, "Spring Yard Zone - Act 1")
+            }
+            LevelID::Sonic1_SpringYardAct2 => {
+                LevelInfo::act(SONIC_1, "Spring Yard Zone", 2, "Spring Yard Zone - Act 2")
+            }
+            LevelID::Sonic1_SpringYardAct3 => {
+                LevelInfo::boss(SONIC_1, "Spring Yard Zone", 3, "Spring Yard Zone - Act 3")
+            }
+            LevelID::Sonic1_LabyrinthAct1 => {
+                LevelInfo::act(SONIC_1, "Labyrinth Zone", 1, "Labyrinth Zone - Act 1")
+            }
+            LevelID::Sonic1_LabyrinthAct2 => {
+                LevelInfo::act(SONIC_1, "Labyrinth Zone", 2, "Labyrinth Zone - Act 2")
+            }
+            LevelID::Sonic1_LabyrinthAct3 => {
+                LevelInfo::boss(SONIC_1, "Labyrinth Zone", 3, "Labyrinth Zone - Act 3")
+            }
+            LevelID::Sonic1_StarLightAct1 => {
+                LevelInfo::act(SONIC_1, "Star Light Zone", 1, "Star Light Zone - Act 1")
+            }
+            LevelID::Sonic1_StarLightAct2 => {
+                LevelInfo::act(SONIC_1, "Star Light Zone", 2, "Star Light Zone - Act 2")
+            }
+            LevelID::Sonic1_StarLightAct3 => {
+                LevelInfo::boss(SONIC_1, "Star Light Zone", 3, "Star Light Zone - Act 3")
+            }
+            LevelID::Sonic1_ScrapBrainAct1 => {
+                LevelInfo::act(SONIC_1, "Scrap Brain Zone", 1, "Scrap Brain Zone - Act 1")
+            }
+            LevelID::Sonic1_ScrapBrainAct2 => {
+                LevelInfo::act(SONIC_1, "Scrap Brain Zone", 2, "Scrap Brain Zone - Act 2")
+            }
+            LevelID::Sonic1_ScrapBrainAct3 => {
+                LevelInfo::act(SONIC_1, "Scrap Brain Zone", 3, "Scrap Brain Zone - Act 3")
+            }
+            LevelID::Sonic1_FinalZone => LevelInfo::boss(SONIC_1, "Final Zone", 1, "Final Zone"),
+            LevelID::Sonic1_Ending => LevelInfo::ending(SONIC_1),
+            LevelID::Sonic2_TitleScreen => LevelInfo::other(SONIC_2, "Title Screen"),
+            LevelID::Sonic2_EmeraldHillAct1 => {
+                LevelInfo::act(SONIC_2, "Emerald Hill Zone", 1, "Emerald Hill Zone - Act 1")
+            }
+            LevelID::Sonic2_EmeraldHillAct2 => {
+                LevelInfo::boss(SONIC_2, "Emerald Hill Zone", 2, "Emerald Hill Zone - Act 2")
+            }
+            LevelID::Sonic2_ChemicalPlantAct1 => LevelInfo::act(
+                SONIC_2,
+                "Chemical Plant Zone",
+                1,
+                "Chemical Plant Zone - Act 1",
+            ),
+            LevelID::Sonic2_ChemicalPlantAct2 => LevelInfo::boss(
+                SONIC_2,
+                "Chemical Plant Zone",
+                2,
+                "Chemical Plant Zone - Act 2",
+            ),
+            LevelID::Sonic2_AquaticRuinAct1 => {
+                LevelInfo::act(SONIC_2, "Aquatic Ruin Zone", 1, "Aquatic Ruin Zone - Act 1")
+            }
+            LevelID::Sonic2_AquaticRuinAct2 => {
+                LevelInfo::boss(SONIC_2, "Aquatic Ruin Zone", 2, "Aquatic Ruin Zone - Act 2")
+            }
+            LevelID::Sonic2_CasinoNightAct1 => {
+                LevelInfo::act(SONIC_2, "Casino Night Zone", 1, "Casino Night Zone - Act 1")
+            }
+            LevelID::Sonic2_CasinoNightAct2 => {
+                LevelInfo::boss(SONIC_2, "Casino Night Zone", 2, "Casino Night Zone - Act 2")
+            }
+            LevelID::Sonic2_HillTopAct1 => {
+                LevelInfo::act(SONIC_2, "Hill Top Zone", 1, "Hill Top Zone - Act 1")
+            }
+            LevelID::Sonic2_HillTopAct2 => {
+                LevelInfo::boss(SONIC_2, "Hill Top Zone", 2, "Hill Top Zone - Act 2")
+            }
+            LevelID::Sonic2_MysticCaveAct1 => {
+                LevelInfo::act(SONIC_2, "Mystic Cave Zone", 1, "Mystic Cave Zone - Act 1")
+            }
+            LevelID::Sonic2_MysticCaveAct2 => {
+                LevelInfo::boss(SONIC_2, "Mystic Cave Zone", 2, "Mystic Cave Zone - Act 2")
+            }
+            LevelID::Sonic2_OilOceanAct1 => {
+                LevelInfo::act(SONIC_2, "Oil Ocean Zone", 1, "Oil Ocean Zone - Act 1")
+            }
+            LevelID::Sonic2_OilOceanAct2 => {
+                LevelInfo::boss(SONIC_2, "Oil Ocean Zone", 2, "Oil Ocean Zone - Act 2")
+            }
+            LevelID::Sonic2_MetropolisAct1 => {
+                LevelInfo::act(SONIC_2, "Metropolis Zone", 1, "Metropolis Zone - Act 1")
+            }
+            LevelID::Sonic2_MetropolisAct2 => {
+                LevelInfo::act(SONIC_2, "Metropolis Zone", 2, "Metropolis Zone - Act 2")
+            }
+            LevelID::Sonic2_MetropolisAct3 => {
+                LevelInfo::boss(SONIC_2, "Metropolis Zone", 3, "Metropolis Zone - Act 3")
+            }
+            LevelID::Sonic2_SkyChase => {
+                LevelInfo::act(SONIC_2, "Sky Chase Zone", 1, "Sky Chase Zone")
+            }
+            LevelID::Sonic2_WingFortress => {
+                LevelInfo::boss(SONIC_2, "Wing Fortress Zone", 1, "Wing Fortress Zone")
+            }
+            LevelID::Sonic2_DeathEgg => {
+                LevelInfo::boss(SONIC_2, "Death Egg Zone", 1, "Death Egg Zone")
+            }
+            LevelID::Sonic2_Ending => LevelInfo::ending(SONIC_2),
+            LevelID::Sonic3_TitleScreen => LevelInfo::other(SONIC_3, "Title Screen"),
+            LevelID::Sonic3_SaveSelect => LevelInfo::other(SONIC_3, "Save Select"),
+            LevelID::Sonic3_AngelIslandAct1 => {
+                LevelInfo::act(SONIC_3, "Angel Island Zone", 1, "Angel Island Zone - Act 1")
+            }
+            LevelID::Sonic3_AngelIslandAct2 => {
+                LevelInfo::boss(SONIC_3, "Angel Island Zone", 2, "Angel Island Zone - Act 2")
+            }
+            LevelID::Sonic3_HydrocityAct1 => {
+                LevelInfo::act(SONIC_3, "Hydrocity Zone", 1, "Hydrocity Zone - Act 1")
+            }
+            LevelID::Sonic3_HydrocityAct2 => {
+                LevelInfo::boss(SONIC_3, "Hydrocity Zone", 2, "Hydrocity Zone - Act 2")
+            }
+            LevelID::Sonic3_MarbleGardenAct1 => LevelInfo::act(
+                SONIC_3,
+                "Marble Garden Zone",
+                1,
+                "Marble Garden Zone - Act 1",
+            ),
+            LevelID::Sonic3_MarbleGardenAct2 => LevelInfo::boss(
+                SONIC_3,
+                "Marble Garden Zone",
+                2,
+                "Marble Garden Zone - Act 2",
+            ),
+            LevelID::Sonic3_CarnivalNightAct1 => LevelInfo::act(
+                SONIC_3,
+                "Carnival Night Zone",
+                1,
+                "Carnival Night Zone - Act 1",
+            ),
+            LevelID::Sonic3_CarnivalNightAct2 => LevelInfo::boss(
+                SONIC_3,
+                "Carnival Night Zone",
+                2,
+                "Carnival Night Zone - Act 2",
+            ),
+            LevelID::Sonic3_IceCapAct1 => {
+                LevelInfo::act(SONIC_3, "IceCap Zone", 1, "IceCap Zone - Act 1")
+            }
+            LevelID::Sonic3_IceCapAct2 => {
+                LevelInfo::boss(SONIC_3, "IceCap Zone", 2, "IceCap Zone - Act 2")
+            }
+            LevelID::Sonic3_LaunchBaseAct1 => {
+                LevelInfo::act(SONIC_3, "Launch Base Zone", 1, "Launch Base Zone - Act 1")
+            }
+            LevelID::Sonic3_LaunchBaseAct2 => {
+                LevelInfo::boss(SONIC_3, "Launch Base Zone", 2, "Launch Base Zone - Act 2")
+            }
+            LevelID::Sonic3_MushroomHillAct1 => LevelInfo::act(
+                SONIC_3,
+                "Mushroom Hill Zone",
+                1,
+                "Mushroom Hill Zone - Act 1",
+            ),
+            LevelID::Sonic3_MushroomHillAct2 => LevelInfo::boss(
+                SONIC_3,
+                "Mushroom Hill Zone",
+                2,
+                "Mushroom Hill Zone - Act 2",
+            ),
+            LevelID::Sonic3_FlyingBatteryAct1 => LevelInfo::act(
+                SONIC_3,
+                "Flying Battery Zone",
+                1,
+                "Flying Battery Zone - Act 1",
+            ),
+            LevelID::Sonic3_FlyingBatteryAct2 => LevelInfo::boss(
+                SONIC_3,
+                "Flying Battery Zone",
+                2,
+                "Flying Battery Zone - Act 2",
+            ),
+            LevelID::Sonic3_SandopolisAct1 => {
+                LevelInfo::act(SONIC_3, "Sandopolis Zone", 1, "Sandopolis Zone - Act 1")
+            }
+            LevelID::Sonic3_SandopolisAct2 => {
+                LevelInfo::boss(SONIC_3, "Sandopolis Zone", 2, "Sandopolis Zone - Act 2")
+            }
+            LevelID::Sonic3_LavaReefAct1 => {
+                LevelInfo::act(SONIC_3, "Lava Reef Zone", 1, "Lava Reef Zone - Act 1")
+            }
+            LevelID::Sonic3_LavaReefAct2 => {
+                LevelInfo::boss(SONIC_3, "Lava Reef Zone", 2, "Lava Reef Zone - Act 2")
+            }
+            LevelID::Sonic3_HiddenPalace => {
+                LevelInfo::boss(SONIC_3, "Hidden Palace Zone", 1, "Hidden Palace Zone")
+            }
+            LevelID::Sonic3_SkySanctuary => {
+                LevelInfo::boss(SONIC_3, "Sky Sanctuary Zone", 1, "Sky Sanctuary Zone")
+            }
+            LevelID::Sonic3_DeathEggAct1 => {
+                LevelInfo::act(SONIC_3, "Death Egg Zone", 1, "Death Egg Zone - Act 1")
+            }
+            LevelID::Sonic3_DeathEggAct2 => {
+                LevelInfo::boss(SONIC_3, "Death Egg Zone", 2, "Death Egg Zone - Act 2")
+            }
+            LevelID::Sonic3_Doomsday => {
+                LevelInfo::boss(SONIC_3, "The Doomsday Zone", 1, "The Doomsday Zone")
+            }
+            LevelID::Sonic3_Ending => LevelInfo::ending(SONIC_3),
+            LevelID::SonicCD_TitleScreen => LevelInfo::other(SONIC_CD, "Title Screen"),
+            LevelID::SonicCD_PalmtreePanicAct1 => LevelInfo::act(
+                SONIC_CD,
+                "Palmtree Panic Zone",
+                1,
+                "Palmtree Panic Zone - Act 1",
+            ),
+            LevelID::SonicCD_PalmtreePanicAct2 => LevelInfo::act(
+                SONIC_CD,
+                "Palmtree Panic Zone",
+                2,
+                "Palmtree Panic Zone - Act 2",
+            ),
+            LevelID::SonicCD_PalmtreePanicAct3 => LevelInfo::boss(
+                SONIC_CD,
+                "Palmtree Panic Zone",
+                3,
+                "Palmtree Panic Zone - Act 3",
+            ),
+            LevelID::SonicCD_CollisionChaosAct1 => LevelInfo::act(
+                SONIC_CD,
+                "Collision Chaos Zone",
+                1,
+                "Collision Chaos Zone - Act 1",
+            ),
+            LevelID::SonicCD_CollisionChaosAct2 => LevelInfo::act(
+                SONIC_CD,
+                "Collision Chaos Zone",
+                2,
+                "Collision Chaos Zone - Act 2",
+            ),
+            LevelID::SonicCD_CollisionChaosAct3 => LevelInfo::boss(
+                SONIC_CD,
+                "Collision Chaos Zone",
+                3,
+                "Collision Chaos Zone - Act 3",
+            ),
+            LevelID::SonicCD_TidalTempestAct1 => LevelInfo::act(
+                SONIC_CD,
+                "Tidal Tempest Zone",
+                1,
+                "Tidal Tempest Zone - Act 1",
+            ),
+            LevelID::SonicCD_TidalTempestAct2 => LevelInfo::act(
+                SONIC_CD,
+                "Tidal Tempest Zone",
+                2,
+                "Tidal Tempest Zone - Act 2",
+            ),
+            LevelID::SonicCD_TidalTempestAct3 => LevelInfo::boss(
+                SONIC_CD,
+                "Tidal Tempest Zone",
+                3,
+                "Tidal Tempest Zone - Act 3",
+            ),
+            LevelID::SonicCD_QuartzQuadrantAct1 => LevelInfo::act(
+                SONIC_CD,
+                "Quartz Quadrant Zone",
+                1,
+                "Quartz Quadrant Zone - Act 1",
+            ),
+            LevelID::SonicCD_QuartzQuadrantAct2 => LevelInfo::act(
+                SONIC_CD,
+                "Quartz Quadrant Zone",
+                2,
+                "Quartz Quadrant Zone - Act 2",
+            ),
+            LevelID::SonicCD_QuartzQuadrantAct3 => LevelInfo::boss(
+                SONIC_CD,
+                "Quartz Quadrant Zone",
+                3,
+                "Quartz Quadrant Zone - Act 3",
+            ),
+            LevelID::SonicCD_WackyWorkbenchAct1 => LevelInfo::act(
+                SONIC_CD,
+                "Wacky Workbench Zone",
+                1,
+                "Wacky Workbench Zone - Act 1",
+            ),
+            LevelID::SonicCD_WackyWorkbenchAct2 => LevelInfo::act(
+                SONIC_CD,
+                "Wacky Workbench Zone",
+                2,
+                "Wacky Workbench Zone - Act 2",
+            ),
+            LevelID::SonicCD_WackyWorkbenchAct3 => LevelInfo::boss(
+                SONIC_CD,
+                "Wacky Workbench Zone",
+                3,
+                "Wacky Workbench Zone - Act 3",
+            ),
+            LevelID::SonicCD_StardustSpeedwayAct1 => LevelInfo::act(
+                SONIC_CD,
+                "Stardust Speedway Zone",
+                1,
+                "Stardust Speedway Zone - Act 1",
+            ),
+            LevelID::SonicCD_StardustSpeedwayAct2 => LevelInfo::act(
+                SONIC_CD,
+                "Stardust Speedway Zone",
+                2,
+                "Stardust Speedway Zone - Act 2",
+            ),
+            LevelID::SonicCD_StardustSpeedwayAct3 => LevelInfo::boss(
+                SONIC_CD,
+                "Stardust Speedway Zone",
+                3,
+                "Stardust Speedway Zone - Act 3",
+            ),
+            LevelID::SonicCD_MetallicMadnessAct1 => LevelInfo::act(
+                SONIC_CD,
+                "Metallic Madness Zone",
+                1,
+                "Metallic Madness Zone - Act 1",
+            ),
+            LevelID::SonicCD_MetallicMadnessAct2 => LevelInfo::act(
+                SONIC_CD,
+                "Metallic Madness Zone",
+                2,
+                "Metallic Madness Zone - Act 2",
+            ),
+            LevelID::SonicCD_MetallicMadnessAct3 => LevelInfo::boss(
+                SONIC_CD,
+                "Metallic Madness Zone",
+                3,
+                "Metallic Madness Zone - Act 3",
+            ),
+            LevelID::SonicCD_Ending => LevelInfo::ending(SONIC_CD),
+        }
+    }
+
+    pub const fn game(self) -> Game {
+        self.info().game
+    }
+
+    /// Whether this is a playable act, as opposed to menus, title screens and endings.
+    pub const fn is_act(self) -> bool {
+        matches!(self.info().kind, LevelKind::Act)
+    }
+
+    /// Whether this is the ending of one of the games.
+    pub const fn is_ending(self) -> bool {
+        matches!(self.info().kind, LevelKind::Ending)
+    }
+
+    pub const fn zone(self) -> Option<&'static str> {
+        self.info().zone
+    }
+
+    pub const fn act(self) -> Option<u8> {
+        self.info().act
+    }
+
+    pub const fn is_boss_act(self) -> bool {
+        self.info().boss
+    }
+
+    pub const fn display_name(self) -> &'static str {
+        self.info().name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use std::format;
+
+    const GAMES: [Game; 4] = [SONIC_1, SONIC_CD, SONIC_2, SONIC_3];
+
+    #[test]
+    fn variants_belong_to_the_game_in_their_name() {
+        for &level in LevelID::ALL {
+            let name = format!("{level:?}");
+            let game = match name.split_once('_') {
+                None => NO_GAME,
+                Some(("Sonic1", _)) => SONIC_1,
+                Some(("SonicCD", _)) => SONIC_CD,
+                Some(("Sonic2", _)) => SONIC_2,
+                Some(("Sonic3", _)) => SONIC_3,
+                Some((prefix, _)) => panic!("{name} has an unknown prefix {prefix}"),
+            };
+            assert_eq!(level.game(), game, "{name}");
+        }
+    }
+
+    #[test]
+    fn acts_of_a_zone_follow_each_other() {
+        for (i, &level) in LevelID::ALL.iter().enumerate() {
+            let info = level.info();
+            assert!(!info.name.is_empty(), "{level:?}");
+            assert_eq!(info.act.is_some(), level.is_act(), "{level:?}");
+            assert!(!info.boss || level.is_act(), "{level:?}");
+
+            let Some(act) = info.act else {
+                continue;
+            };
+            assert!(info.zone.is_some(), "{level:?}");
+            assert!((1..=3).contains(&act), "{level:?}");
+
+            if act > 1 {
+                let previous = LevelID::ALL[i - 1].info();
+                assert_eq!(previous.act, Some(act - 1), "{level:?}");
+                assert_eq!(previous.zone, info.zone, "{level:?}");
+                assert!(!previous.boss, "{level:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn every_game_has_a_title_screen_and_one_ending() {
+        for game in GAMES {
+            let levels = || {
+                LevelID::ALL
+                    .iter()
+                    .filter(move |level| level.game() == game)
+            };
+            assert_eq!(levels().filter(|level| level.is_ending()).count(), 1);
+            assert!(levels().any(|level| level.display_name() == "Title Screen"));
+        }
+    }
+
+    #[test]
+    fn kinds_are_exclusive() {
+        for &level in LevelID::ALL {
+            let kinds = [level.is_act(), level.is_ending()];
+            assert!(kinds.iter().filter(|&&val| val).count() <= 1, "{level:?}");
+        }
+
+        assert!(LevelID::Sonic2_SkyChase.is_act());
+        assert!(LevelID::Sonic3_Doomsday.is_act());
+        assert!(LevelID::SonicCD_Ending.is_ending());
+        assert!(!LevelID::Sonic3_SaveSelect.is_act());
+        assert!(!LevelID::MainMenu.is_act());
+    }
+}
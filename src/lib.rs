@@ -1,5 +1,6 @@
 #![no_std]
 #![feature(type_alias_impl_trait, const_async_blocks)]
+#![cfg_attr(test, feature(variant_count))]
 #![warn(
     clippy::complexity,
     clippy::correctness,
@@ -9,8 +10,9 @@
     rust_2018_idioms
 )]
 
+use core::sync::atomic::{AtomicBool, Ordering};
+
 use asr::{
-    file_format::pe,
     future::{next_tick, retry},
     signature::Signature,
     time::Duration,
@@ -19,18 +21,30 @@ use asr::{
     Address, Address64, Process,
 };
 
+mod level;
+mod retroengine;
+mod route;
+use route::{CharacterEdge, RouteEdge, SkippedSplits, SplitGranularity};
 mod rtti;
-use rtti::Rtti;
+use rtti::{Demangled, Rtti};
 mod sonic1;
 mod sonic2;
 mod sonic3;
+use sonic3::Character;
 mod soniccd;
+use soniccd::TimePeriod;
+mod version;
+use version::ExecutableInfo;
 
 asr::panic_handler!();
 asr::async_main!(nightly);
 
 const PROCESS_NAMES: &[&str] = &["SonicOrigins.exe"];
 
+/// Whether `address_error` prints anything. The scan is retried every tick while the game
+/// is starting up, so only the first failed attempt after attaching is reported.
+static REPORT_ADDRESS_ERRORS: AtomicBool = AtomicBool::new(true);
+
 async fn main() {
     let settings = Settings::register();
 
@@ -44,7 +58,14 @@ async fn main() {
                 let mut watchers = Watchers::default();
 
                 // Perform memory scanning to look for the addresses we need
-                let mut addresses = retry(|| Addresses::init(&process)).await;
+                REPORT_ADDRESS_ERRORS.store(true, Ordering::Relaxed);
+                let mut addresses = retry(|| {
+                    let addresses = Addresses::init(&process);
+                    REPORT_ADDRESS_ERRORS.store(false, Ordering::Relaxed);
+                    addresses
+                })
+                .await;
+                addresses.print(&process);
 
                 loop {
                     // Splitting logic. Adapted from OG LiveSplit:
@@ -53,7 +74,7 @@ async fn main() {
                     // 2. If the timer is currently either running or paused, then the isLoading, gameTime, and reset actions will be run.
                     // 3. If reset does not return true, then the split action will be run.
                     // 4. If the timer is currently not running (and not paused), then the start action will be run.
-                    update_loop(&process, &mut addresses, &mut watchers);
+                    update_loop(&process, &mut addresses, &mut watchers, &settings);
 
                     let timer_state = timer::state();
                     if timer_state == TimerState::Running || timer_state == TimerState::Paused {
@@ -72,7 +93,14 @@ async fn main() {
                         if reset(&watchers, &settings) {
                             timer::reset()
                         } else if split(&watchers, &settings) {
-                            timer::split()
+                            let skipped = skipped_splits(&watchers, &settings);
+                            for _ in 0..skipped.before {
+                                timer::skip_split()
+                            }
+                            timer::split();
+                            for _ in 0..skipped.after {
+                                timer::skip_split()
+                            }
                         }
                     }
 
@@ -98,6 +126,30 @@ async fn main() {
 
 #[derive(asr::user_settings::Settings)]
 struct Settings {
+    #[default = false]
+    /// ---------- TIMING ----------
+    _timing: bool,
+    #[default = false]
+    /// Use in-game time (sum of the stage timers) instead of real time without the results tally
+    igt: bool,
+    #[default = false]
+    /// Remove the launcher's loading screens from game time
+    loading_screens: bool,
+    #[default = false]
+    /// ---------- LEVEL DETECTION ----------
+    _level_detection: bool,
+    #[default = true]
+    /// Ignore an unknown level or the main menu showing up for up to 10 ticks between two acts
+    flicker_filter: bool,
+    #[default = false]
+    /// Extend that filter to 60 ticks
+    flicker_filter_long: bool,
+    #[default = false]
+    /// ---------- SPECIAL STAGES ----------
+    _special_stages: bool,
+    #[default = false]
+    /// Split when a special stage awards a Chaos Emerald, Super Emerald or Time Stone
+    emerald_split: bool,
     #[default = false]
     /// ---------- STORY MODE ----------
     _start: bool,
@@ -105,6 +157,21 @@ struct Settings {
     /// --> Enable auto start
     story_start: bool,
     #[default = true]
+    /// --> Enable auto reset
+    story_reset: bool,
+    #[default = false]
+    /// --> Split once per game instead of on every act
+    story_game_splits: bool,
+    #[default = false]
+    /// --> Only split at the end of each zone
+    story_zone_splits: bool,
+    #[default = false]
+    /// --> Remove the cutscenes between games from game time
+    story_cutscenes: bool,
+    #[default = false]
+    /// --> Remove the loads between games from game time
+    story_loads: bool,
+    #[default = true]
     /// Sonic 1 - Green Hill Zone - Act 1
     story_s1_green_hill_1: bool,
     #[default = true]
@@ -360,12 +427,57 @@ struct Settings {
     /// Sonic 3&K - Doomsday Zone
     story_s3_doomsday: bool,
     #[default = false]
+    /// ---------- BOSS RUSH ----------
+    _boss_rush: bool,
+    #[default = true]
+    /// --> Enable auto start
+    boss_rush_start: bool,
+    #[default = true]
+    /// Split after each boss is defeated
+    boss_rush_boss: bool,
+    #[default = true]
+    /// Final split when the results screen appears
+    boss_rush_results: bool,
+    #[default = false]
+    /// ---------- BLUE SPHERES ----------
+    _blue_spheres: bool,
+    #[default = true]
+    /// --> Enable auto start
+    blue_spheres_start: bool,
+    #[default = true]
+    /// Split when a stage is cleared
+    blue_spheres_stage: bool,
+    #[default = false]
+    /// Only split every 5 stages
+    blue_spheres_every_5: bool,
+    #[default = false]
+    /// Only split every 10 stages
+    blue_spheres_every_10: bool,
+    #[default = false]
+    /// Final split after stage 10
+    blue_spheres_stop_10: bool,
+    #[default = false]
+    /// Final split after stage 50
+    blue_spheres_stop_50: bool,
+    #[default = false]
+    /// Final split after stage 100
+    blue_spheres_stop_100: bool,
+    #[default = false]
     /// ---------- SONIC 1 ----------
     _sonic1: bool,
     #[default = true]
     /// --> Enable auto start
     s1_start: bool,
     #[default = true]
+    /// --> Enable auto reset
+    s1_reset: bool,
+    #[default = false]
+    /// --> Only split at the end of each zone
+    s1_zone_splits: bool,
+    #[default = false]
+    /// --> Only split at the end of the game
+    s1_game_splits: bool,
+    #[default = true]
     /// Green Hill Zone - Act 1
     s1_green_hill_1: bool,
     #[default = true]
@@ -429,6 +541,18 @@ struct Settings {
     /// --> Enable auto start
     scd_start: bool,
     #[default = true]
+    /// --> Enable auto reset
+    scd_reset: bool,
+    #[default = false]
+    /// --> Only split at the end of each zone
+    scd_zone_splits: bool,
+    #[default = false]
+    /// --> Only split at the end of the game
+    scd_game_splits: bool,
+    #[default = false]
+    /// --> Good Future: only split on acts finished in a Good Future
+    scd_good_future: bool,
+    #[default = true]
     /// Palmtree Panic Zone - Act 1
     scd_palmtree_panic_1: bool,
     #[default = true]
@@ -498,6 +622,15 @@ struct Settings {
     /// --> Enable auto start
     s2_start: bool,
     #[default = true]
+    /// --> Enable auto reset
+    s2_reset: bool,
+    #[default = false]
+    /// --> Only split at the end of each zone
+    s2_zone_splits: bool,
+    #[default = false]
+    /// --> Only split at the end of the game
+    s2_game_splits: bool,
+    #[default = true]
     /// Emerald Hill Zone - Act 1
     s2_emerald_hill_1: bool,
     #[default = true]
@@ -564,6 +697,15 @@ struct Settings {
     /// --> Enable auto start
     s3_start: bool,
     #[default = true]
+    /// --> Enable auto reset
+    s3_reset: bool,
+    #[default = false]
+    /// --> Only split at the end of each zone
+    s3_zone_splits: bool,
+    #[default = false]
+    /// --> Only split at the end of the game
+    s3_game_splits: bool,
+    #[default = true]
     /// Angel Island Zone - Act 1
     s3_angel_island_1: bool,
     #[default = true]
@@ -640,19 +782,91 @@ struct Settings {
     s3_doomsday: bool,
 }
 
+impl Settings {
+    fn split_granularity(&self, game: Game, story: bool) -> SplitGranularity {
+        let (zone_splits, game_splits) = match game {
+            _ if story => (self.story_zone_splits, false),
+            Game::Sonic1 => (self.s1_zone_splits, self.s1_game_splits),
+            Game::SonicCD => (self.scd_zone_splits, self.scd_game_splits),
+            Game::Sonic2 => (self.s2_zone_splits, self.s2_game_splits),
+            Game::Sonic3 => (self.s3_zone_splits, self.s3_game_splits),
+            Game::GameGear | Game::None => (false, false),
+        };
+
+        if game_splits {
+            SplitGranularity::Game
+        } else if zone_splits {
+            SplitGranularity::Zone
+        } else {
+            SplitGranularity::Act
+        }
+    }
+
+    fn flicker_ticks(&self) -> u32 {
+        if self.flicker_filter_long {
+            60
+        } else if self.flicker_filter {
+            10
+        } else {
+            0
+        }
+    }
+
+    fn blue_spheres_split_interval(&self) -> u16 {
+        if self.blue_spheres_every_10 {
+            10
+        } else if self.blue_spheres_every_5 {
+            5
+        } else {
+            1
+        }
+    }
+
+    fn blue_spheres_stop_stage(&self) -> Option<u16> {
+        if self.blue_spheres_stop_10 {
+            Some(10)
+        } else if self.blue_spheres_stop_50 {
+            Some(50)
+        } else if self.blue_spheres_stop_100 {
+            Some(100)
+        } else {
+            None
+        }
+    }
+}
+
 #[derive(Default)]
 struct Watchers {
     game_status: Watcher<GameStatus>,
+    front_end_class: Watcher<Address>,
     game: Watcher<Game>,
     game_mode: Watcher<GameMode>,
     act_id: Watcher<LevelID>,
+    level: Watcher<LevelID>,
+    unstable_ticks: u32,
+    last_act: Watcher<LevelID>,
+    ending_reached: Watcher<bool>,
+    story_cutscene: bool,
+    story_game_switch: bool,
     start_trigger: Watcher<bool>,
     is_in_time_bonus: Watcher<bool>,
     demo_mode: Watcher<bool>,
+    character: Watcher<Character>,
+    time_period: Watcher<TimePeriod>,
+    good_future: Watcher<bool>,
+    time_stones: Watcher<u8>,
+    rings: Watcher<u16>,
+    score: Watcher<u32>,
+    lives: Watcher<u8>,
+    chaos_emeralds: Watcher<u8>,
+    super_emeralds: Watcher<u8>,
+    blue_spheres_stage: Watcher<u16>,
+    igt: IgtTracker,
     story_start_flag: bool,
 }
 
 struct Addresses {
+    executable: ExecutableInfo,
     hedgehog_base: Address,
     current_rsdk_game: Address,
     managers: Managers,
@@ -664,50 +878,234 @@ impl Addresses {
         let main_module_base = PROCESS_NAMES
             .iter()
             .find_map(|p| game.get_module_address(p).ok())?;
-        let main_module_size = pe::read_size_of_image(game, main_module_base)? as u64;
-        let main_module_range = (main_module_base, main_module_size);
+        let executable = ExecutableInfo::read(game, main_module_base)?;
+        let main_module_range = (main_module_base, executable.size_of_image as u64);
 
         let hedgehog_base = {
             const SIG: Signature<9> = Signature::new("E8 ?? ?? ?? ?? 44 39 75 48");
-            let ptr = SIG.scan_process_range(game, main_module_range)? + 1;
+            let ptr = SIG
+                .scan_process_range(game, main_module_range)
+                .or_else(|| address_error("Sonic Origins", "hedgehog_base", "signature scan"))?
+                + 1;
             let temp_addr = ptr + 0x4 + game.read::<i32>(ptr).ok()? + 0x3;
             temp_addr + game.read::<i32>(temp_addr).ok()? + 0x4
         };
 
         let current_rsdk_game = {
             const SIG: Signature<14> = Signature::new("89 0D ?? ?? ?? ?? 89 15 ?? ?? ?? ?? C7 05");
-            let ptr = SIG.scan_process_range(game, main_module_range)? + 2;
+            let ptr = SIG
+                .scan_process_range(game, main_module_range)
+                .or_else(|| {
+                    address_error("Sonic Origins", "current_rsdk_game", "signature scan")
+                })?
+                + 2;
             ptr + 0x4 + game.read::<i32>(ptr).ok()?
         };
 
         Some(Self {
+            executable,
             hedgehog_base,
             current_rsdk_game,
             managers: Managers::new(game, main_module_range)?,
-            rtti: Rtti::new(main_module_base),
+            rtti: Rtti::new(main_module_range),
         })
     }
+
+    /// Logs the build of the executable and the table of resolved addresses.
+    fn print(&self, game: &Process) {
+        asr::print_limited::<128>(&format_args!(
+            "SonicOrigins.exe build: timestamp {:#010X}, size {:#X}",
+            self.executable.timestamp, self.executable.size_of_image
+        ));
+        print_address("Sonic Origins", "hedgehog_base", self.hedgehog_base);
+        print_address("Sonic Origins", "current_rsdk_game", self.current_rsdk_game);
+        self.managers.print();
+        GameStatus::report_missing_classes(game, &self.rtti);
+    }
 }
 
 struct Managers {
-    sonic_1: sonic1::Sonic1,
-    sonic_2: sonic2::Sonic2,
-    sonic_cd: soniccd::SonicCD,
-    sonic_3: sonic3::Sonic3,
+    sonic_1: Option<sonic1::Sonic1>,
+    sonic_2: Option<sonic2::Sonic2>,
+    sonic_cd: Option<soniccd::SonicCD>,
+    sonic_3: Option<sonic3::Sonic3>,
 }
 
 impl Managers {
     fn new(process: &Process, main_module_range: (Address, u64)) -> Option<Self> {
-        Some(Self {
-            sonic_1: sonic1::Sonic1::new(process, main_module_range)?,
-            sonic_2: sonic2::Sonic2::new(process, main_module_range)?,
-            sonic_cd: soniccd::SonicCD::new(process, main_module_range)?,
-            sonic_3: sonic3::Sonic3::new(process, main_module_range)?,
-        })
+        let managers = Self {
+            sonic_1: sonic1::Sonic1::new(process, main_module_range),
+            sonic_2: sonic2::Sonic2::new(process, main_module_range),
+            sonic_cd: soniccd::SonicCD::new(process, main_module_range),
+            sonic_3: sonic3::Sonic3::new(process, main_module_range),
+        };
+
+        // Nothing resolving at all usually means the game is still starting up,
+        // so that case is retried instead of being reported
+        let missing = [
+            (Game::Sonic1, managers.sonic_1.is_none()),
+            (Game::Sonic2, managers.sonic_2.is_none()),
+            (Game::SonicCD, managers.sonic_cd.is_none()),
+            (Game::Sonic3, managers.sonic_3.is_none()),
+        ];
+        if missing.iter().all(|&(_, missing)| missing) {
+            return None;
+        }
+
+        for (game, _) in missing.iter().filter(|&&(_, missing)| missing) {
+            asr::print_limited::<128>(&format_args!(
+                "{}: signature scan failed, autosplitting for it is disabled",
+                game.as_str()
+            ));
+        }
+
+        Some(managers)
+    }
+
+    fn print(&self) {
+        if let Some(manager) = &self.sonic_1 {
+            manager.print_addresses();
+        }
+        if let Some(manager) = &self.sonic_2 {
+            manager.print_addresses();
+        }
+        if let Some(manager) = &self.sonic_cd {
+            manager.print_addresses();
+        }
+        if let Some(manager) = &self.sonic_3 {
+            manager.print_addresses();
+        }
+    }
+
+    fn active(&mut self, game: Game) -> ActiveManager<'_> {
+        match game {
+            Game::Sonic1 => self
+                .sonic_1
+                .as_mut()
+                .map_or(ActiveManager::None, ActiveManager::Sonic1),
+            Game::Sonic2 => self
+                .sonic_2
+                .as_mut()
+                .map_or(ActiveManager::None, ActiveManager::Sonic2),
+            Game::SonicCD => self
+                .sonic_cd
+                .as_mut()
+                .map_or(ActiveManager::None, ActiveManager::SonicCD),
+            Game::Sonic3 => self
+                .sonic_3
+                .as_mut()
+                .map_or(ActiveManager::None, ActiveManager::Sonic3),
+            Game::GameGear | Game::None => ActiveManager::None,
+        }
+    }
+}
+
+/// Prints one line of the table of resolved addresses shown on attach.
+fn print_address(owner: &str, name: &str, address: Address) {
+    asr::print_limited::<128>(&format_args!(
+        "{owner:<20} {name:<20} {:#X}",
+        address.value()
+    ));
+}
+
+/// Prints the resolved addresses of a manager, leaving out the extras that are missing.
+fn print_addresses(owner: &str, addresses: &[(&str, Option<Address>)]) {
+    for &(name, address) in addresses {
+        if let Some(address) = address {
+            print_address(owner, name, address);
+        }
+    }
+}
+
+/// Reports an address that could not be resolved, so the manager owning it is not built.
+fn address_error<T>(owner: &str, name: &str, step: &str) -> Option<T> {
+    if REPORT_ADDRESS_ERRORS.load(Ordering::Relaxed) {
+        asr::print_limited::<128>(&format_args!(
+            "{owner}: could not resolve `{name}` ({step})"
+        ));
+    }
+    None
+}
+
+/// Checks that an address lands inside the main module, as a failed read would otherwise
+/// silently resolve to an offset from its base.
+fn module_address(
+    main_module_range: (Address, u64),
+    owner: &str,
+    name: &str,
+    address: Address,
+) -> Option<Address> {
+    let (base, size) = main_module_range;
+    if address < base || address - base >= size {
+        return address_error(owner, name, "module bounds");
+    }
+    Some(address)
+}
+
+/// Scans the main module for a signature that has to match exactly once. A short pattern
+/// can also match unrelated code, and taking its first match would silently resolve to
+/// the wrong data, so a second match is reported as a failure.
+fn scan_unique<const N: usize>(
+    process: &Process,
+    main_module_range: (Address, u64),
+    signature: &Signature<N>,
+    owner: &str,
+    name: &str,
+) -> Option<Address> {
+    match scan_once(process, main_module_range, signature) {
+        Ok(address) => Some(address),
+        Err(step) => address_error(owner, name, step),
+    }
+}
+
+/// Same as `scan_unique`, leaving it to the caller to report a failure.
+fn scan_once<const N: usize>(
+    process: &Process,
+    main_module_range: (Address, u64),
+    signature: &Signature<N>,
+) -> Result<Address, &'static str> {
+    let (base, size) = main_module_range;
+    let Some(address) = signature.scan_process_range(process, main_module_range) else {
+        return Err("signature scan");
+    };
+
+    let rest = address + 1;
+    if signature
+        .scan_process_range(process, (rest, size - (rest - base)))
+        .is_some()
+    {
+        return Err("signature matches more than once");
     }
+    Ok(address)
+}
+
+/// Manager of the game that is currently running, if it could be set up.
+enum ActiveManager<'a> {
+    None,
+    Sonic1(&'a mut sonic1::Sonic1),
+    Sonic2(&'a mut sonic2::Sonic2),
+    SonicCD(&'a mut soniccd::SonicCD),
+    Sonic3(&'a mut sonic3::Sonic3),
 }
 
-fn update_loop(game: &Process, addresses: &mut Addresses, watchers: &mut Watchers) {
+impl ActiveManager<'_> {
+    /// Variables shared by the RetroEngine v4 games.
+    fn engine(&self) -> Option<&retroengine::RetroEngine> {
+        match self {
+            ActiveManager::Sonic1(manager) => Some(&manager.engine),
+            ActiveManager::Sonic2(manager) => Some(&manager.engine),
+            ActiveManager::SonicCD(manager) => Some(&manager.engine),
+            _ => None,
+        }
+    }
+}
+
+fn update_loop(
+    game: &Process,
+    addresses: &mut Addresses,
+    watchers: &mut Watchers,
+    settings: &Settings,
+) {
     let game_status = watchers.game_status.update_infallible({
         let current = match watchers.game_status.pair {
             Some(x) => x.current,
@@ -717,12 +1115,16 @@ fn update_loop(game: &Process, addresses: &mut Addresses, watchers: &mut Watcher
             .read_pointer_path64::<Address64>(addresses.hedgehog_base, &[0, 0x88, 0x0, 0x70, 0x0])
         {
             if let Some(name) = addresses.rtti.lookup(game, addr.into()) {
-                match name.as_bytes() {
-                    b"GameModeMainMenu@game@app@@" => GameStatus::MainMenu,
-                    b"GameModeRetroEngine@game@app@@" => GameStatus::RetroEngine,
-                    b"GameModeGameGear@game@app@@" => GameStatus::GameGear,
-                    _ => current,
+                let class = Demangled::new(name.validate_utf8().unwrap_or_default());
+                if watchers
+                    .front_end_class
+                    .update_infallible(addr.into())
+                    .changed()
+                {
+                    asr::print_limited::<160>(&format_args!("Front-end mode: {class}"));
                 }
+
+                GameStatus::from_class(class).unwrap_or(current)
             } else {
                 current
             }
@@ -745,56 +1147,243 @@ fn update_loop(game: &Process, addresses: &mut Addresses, watchers: &mut Watcher
                 _ => Game::Sonic1,
             },
         },
-        GameStatus::MainMenu | GameStatus::GameGear => Game::None,
+        GameStatus::GameGear => Game::GameGear,
+        GameStatus::MainMenu | GameStatus::Museum => Game::None,
+        // Shown on top of, or between, the games themselves
+        GameStatus::Movie | GameStatus::Loading | GameStatus::Results => match watchers.game.pair {
+            Some(x) => x.current,
+            _ => Game::None,
+        },
     });
 
-    watchers
-        .game_mode
-        .update_infallible(match cur_game.current {
-            Game::Sonic1 => addresses.managers.sonic_1.get_game_mode(game),
-            Game::Sonic2 => addresses.managers.sonic_2.get_game_mode(game),
-            Game::SonicCD => addresses.managers.sonic_cd.get_game_mode(game),
-            Game::Sonic3 => addresses.managers.sonic_3.get_game_mode(game),
-            _ => GameMode::Classic,
-        });
+    let mut active = addresses.managers.active(cur_game.current);
+
+    // The Game Gear titles are only recognised, they have no manager to set up
+    if cur_game.changed()
+        && !matches!(cur_game.current, Game::None | Game::GameGear)
+        && matches!(active, ActiveManager::None)
+    {
+        asr::print_limited::<128>(&format_args!(
+            "{} could not be set up for this build and will not be autosplit",
+            cur_game.current.as_str()
+        ));
+    }
+
+    if cur_game.changed() {
+        timer::set_variable("Game", cur_game.current.as_str());
+    }
+
+    let game_mode = watchers.game_mode.update_infallible(match &mut active {
+        ActiveManager::Sonic1(manager) => manager.get_game_mode(game),
+        ActiveManager::Sonic2(manager) => manager.get_game_mode(game),
+        ActiveManager::SonicCD(manager) => manager.get_game_mode(game),
+        ActiveManager::Sonic3(manager) => manager.get_game_mode(game),
+        _ => GameMode::Classic,
+    });
+
+    if game_mode.changed() {
+        timer::set_variable("Game mode", game_mode.current.as_str());
+    }
 
-    let act_id = watchers.act_id.update_infallible(match cur_game.current {
-        Game::Sonic1 => addresses.managers.sonic_1.get_current_level(game),
-        Game::Sonic2 => addresses.managers.sonic_2.get_current_level(game),
-        Game::SonicCD => addresses.managers.sonic_cd.get_current_level(game),
-        Game::Sonic3 => addresses.managers.sonic_3.get_current_level(game),
+    if game_mode.current == GameMode::Story && (cur_game.changed() || game_mode.changed()) {
+        if let Some(segment) = cur_game.current.story_segment() {
+            timer::set_variable("Story game", segment);
+        }
+    }
+
+    let act_id = watchers.act_id.update_infallible(match &mut active {
+        ActiveManager::Sonic1(manager) => manager.get_current_level(game),
+        ActiveManager::Sonic2(manager) => manager.get_current_level(game),
+        ActiveManager::SonicCD(manager) => manager.get_current_level(game),
+        ActiveManager::Sonic3(manager) => manager.get_current_level(game),
         _ => LevelID::MainMenu,
     });
 
-    watchers
-        .start_trigger
-        .update_infallible(match cur_game.current {
-            Game::Sonic1 => addresses.managers.sonic_1.get_start_trigger(game),
-            Game::Sonic2 => addresses.managers.sonic_2.get_start_trigger(game),
-            Game::SonicCD => addresses.managers.sonic_cd.get_start_trigger(game),
-            Game::Sonic3 => addresses.managers.sonic_3.get_start_trigger(game),
-            _ => false,
-        });
+    // A bad read or a loading frame can briefly show an unmapped level or the main menu,
+    // so those only replace the last real level once they lasted longer than the filter
+    if matches!(act_id.current, LevelID::Unknown | LevelID::MainMenu) {
+        watchers.unstable_ticks = watchers.unstable_ticks.saturating_add(1);
+    } else {
+        watchers.unstable_ticks = 0;
+    }
+    let level = match watchers.level.pair {
+        Some(x)
+            if watchers.unstable_ticks != 0
+                && watchers.unstable_ticks <= settings.flicker_ticks() =>
+        {
+            x.current
+        }
+        _ => act_id.current,
+    };
+    let level = watchers.level.update_infallible(level);
+
+    if level.changed() {
+        timer::set_variable("Level", level.current.display_name());
+        timer::set_variable("Zone", level.current.zone().unwrap_or("-"));
+        match level.current.act() {
+            Some(act) => timer::set_variable_int("Act", act),
+            _ => timer::set_variable("Act", "-"),
+        }
+    }
+
+    // An unmapped level is entered from the middle of an act, or between two acts, as
+    // with the special stages, so the route keeps following the last act that was played
+    let last_act = match watchers.last_act.pair {
+        Some(x) if level.current == LevelID::Unknown => x.current,
+        _ => level.current,
+    };
+    watchers.last_act.update_infallible(last_act);
+
+    // Remembered until the game changes, so moving on to the next game in a Story run
+    // only counts as the end of the previous one if its ending was never seen
+    let ending_reached = !cur_game.changed()
+        && (level.current.is_ending()
+            || watchers.ending_reached.pair.is_some_and(|val| val.current));
+    watchers.ending_reached.update_infallible(ending_reached);
+
+    let stage_loading = active
+        .engine()
+        .is_some_and(|engine| engine.is_stage_loading(game));
+
+    // A Story run hands off to the next game by leaving the RSDK engine for a cutscene,
+    // then booting the next game, which lasts until its first act is running
+    let story = game_mode.old == GameMode::Story || game_mode.current == GameMode::Story;
+    watchers.story_cutscene = story && game_status.current == GameStatus::Movie;
+    watchers.story_game_switch = !game_status.current.is_menu()
+        && if watchers.story_game_switch {
+            game_status.current != GameStatus::RetroEngine
+                || !level.current.is_act()
+                || stage_loading
+        } else {
+            story
+                && (ending_reached && game_status.changed_from(&GameStatus::RetroEngine)
+                    || cur_game.changed()
+                        && cur_game.old.story_segment().is_some()
+                        && cur_game.current.story_segment().is_some())
+        };
+
+    watchers.start_trigger.update_infallible(match &mut active {
+        ActiveManager::Sonic1(manager) => manager.get_start_trigger(game),
+        ActiveManager::Sonic2(manager) => manager.get_start_trigger(game),
+        ActiveManager::SonicCD(manager) => manager.get_start_trigger(game),
+        ActiveManager::Sonic3(manager) => manager.get_start_trigger(game),
+        _ => false,
+    });
 
     watchers
         .is_in_time_bonus
-        .update_infallible(match cur_game.current {
-            Game::Sonic1 => addresses.managers.sonic_1.is_in_time_bonus(game),
-            Game::Sonic2 => addresses.managers.sonic_2.is_in_time_bonus(game),
-            Game::SonicCD => addresses.managers.sonic_cd.is_in_time_bonus(game),
-            Game::Sonic3 => addresses.managers.sonic_3.is_in_time_bonus(),
+        .update_infallible(match &mut active {
+            ActiveManager::Sonic1(manager) => manager.is_in_time_bonus(game),
+            ActiveManager::Sonic2(manager) => manager.is_in_time_bonus(game),
+            ActiveManager::SonicCD(manager) => manager.is_in_time_bonus(game),
+            ActiveManager::Sonic3(manager) => manager.is_in_time_bonus(game),
             _ => false,
         });
 
-    watchers
-        .demo_mode
-        .update_infallible(match cur_game.current {
-            Game::Sonic1 => addresses.managers.sonic_1.is_demo_mode(game),
-            Game::Sonic2 => addresses.managers.sonic_2.is_demo_mode(game),
-            Game::SonicCD => addresses.managers.sonic_cd.is_demo_mode(game),
-            Game::Sonic3 => addresses.managers.sonic_3.is_demo_mode(),
-            _ => false,
-        });
+    watchers.demo_mode.update_infallible(match &mut active {
+        ActiveManager::Sonic1(manager) => manager.is_demo_mode(game),
+        ActiveManager::Sonic2(manager) => manager.is_demo_mode(game),
+        ActiveManager::SonicCD(manager) => manager.is_demo_mode(game),
+        ActiveManager::Sonic3(manager) => manager.is_demo_mode(game),
+        _ => false,
+    });
+
+    let character = watchers.character.update(match &mut active {
+        ActiveManager::Sonic3(manager) => manager.get_character(game),
+        _ => None,
+    });
+
+    if let Some(character) = character.filter(|val| val.changed()) {
+        timer::set_variable("Character", character.current.as_str());
+    }
+
+    let time_period = watchers.time_period.update_infallible(match &mut active {
+        ActiveManager::SonicCD(manager) => manager.get_time_period(game),
+        _ => TimePeriod::Present,
+    });
+
+    if time_period.changed() {
+        timer::set_variable("Time period", time_period.current.as_str());
+    }
+
+    watchers.good_future.update(match &mut active {
+        ActiveManager::SonicCD(manager) => manager.is_good_future(game),
+        _ => Some(false),
+    });
+
+    let time_stones = watchers.time_stones.update(match &mut active {
+        ActiveManager::SonicCD(manager) => manager.get_time_stones(game),
+        _ => None,
+    });
+
+    if let Some(time_stones) = time_stones.filter(|val| val.changed()) {
+        timer::set_variable_int("Time stones", time_stones.current);
+    }
+
+    let rings = watchers.rings.update(match &mut active {
+        ActiveManager::Sonic3(manager) => manager.get_rings(game),
+        active => active.engine().and_then(|engine| engine.get_rings(game)),
+    });
+
+    if let Some(rings) = rings.filter(|val| val.changed()) {
+        timer::set_variable_int("Rings", rings.current);
+    }
+
+    let score = watchers
+        .score
+        .update(active.engine().and_then(|engine| engine.get_score(game)));
+
+    if let Some(score) = score.filter(|val| val.changed()) {
+        timer::set_variable_int("Score", score.current);
+    }
+
+    let lives = watchers
+        .lives
+        .update(active.engine().and_then(|engine| engine.get_lives(game)));
+
+    if let Some(lives) = lives.filter(|val| val.changed()) {
+        timer::set_variable_int("Lives", lives.current);
+    }
+
+    let chaos_emeralds = watchers.chaos_emeralds.update(match &mut active {
+        ActiveManager::Sonic1(manager) => manager.get_chaos_emeralds(game),
+        ActiveManager::Sonic2(manager) => manager.get_chaos_emeralds(game),
+        ActiveManager::Sonic3(manager) => manager.get_chaos_emeralds(game),
+        _ => None,
+    });
+
+    if let Some(chaos_emeralds) = chaos_emeralds.filter(|val| val.changed()) {
+        timer::set_variable_int("Chaos emeralds", chaos_emeralds.current);
+    }
+
+    let super_emeralds = watchers.super_emeralds.update(match &mut active {
+        ActiveManager::Sonic3(manager) => manager.get_super_emeralds(game),
+        _ => None,
+    });
+
+    if let Some(super_emeralds) = super_emeralds.filter(|val| val.changed()) {
+        timer::set_variable_int("Super emeralds", super_emeralds.current);
+    }
+
+    watchers.blue_spheres_stage.update(match &mut active {
+        ActiveManager::Sonic3(manager) => manager.get_blue_spheres_stage(game),
+        _ => None,
+    });
+
+    let stage_time = match &mut active {
+        ActiveManager::Sonic3(manager) => manager.get_stage_time(game),
+        active => active
+            .engine()
+            .and_then(|engine| engine.get_stage_time(game)),
+    };
+
+    if timer::state() == TimerState::NotRunning {
+        watchers.igt.reset();
+    } else {
+        watchers
+            .igt
+            .update(act_id.current, game_status.current, stage_time);
+    }
 
     if act_id.current == LevelID::MainMenu {
         watchers.story_start_flag = true;
@@ -802,6 +1391,9 @@ fn update_loop(game: &Process, addresses: &mut Addresses, watchers: &mut Watcher
 }
 
 fn start(watchers: &mut Watchers, settings: &Settings) -> bool {
+    if watchers.demo_mode.pair.is_some_and(|val| val.current) {
+        return false;
+    }
     let Some(game) = &watchers.game.pair else {
         return false;
     };
@@ -849,6 +1441,21 @@ fn start(watchers: &mut Watchers, settings: &Settings) -> bool {
         && start_trigger.changed_to(&true)
     {
         settings.s3_start
+    } else if game_mode.current == GameMode::BossRush
+        && watchers
+            .act_id
+            .pair
+            .is_some_and(|val| !val.old.is_act() && val.current.is_act())
+    {
+        settings.boss_rush_start
+    } else if (game_mode.current == GameMode::BlueSpheresClassic
+        || game_mode.current == GameMode::BlueSpheresNew)
+        && watchers
+            .blue_spheres_stage
+            .pair
+            .is_some_and(|val| val.changed_from(&0))
+    {
+        settings.blue_spheres_start
     } else {
         false
     }
@@ -861,556 +1468,201 @@ fn split(watchers: &Watchers, settings: &Settings) -> bool {
     let Some(game_mode) = &watchers.game_mode.pair else {
         return false;
     };
-    let Some(level_id) = &watchers.act_id.pair else {
+    let Some(level_id) = &watchers.level.pair else {
+        return false;
+    };
+    let Some(last_act) = &watchers.last_act.pair else {
         return false;
     };
 
-    if game_mode.current == GameMode::Classic
-        || game_mode.current == GameMode::Mirror
-        || game_mode.current == GameMode::Anniversary
-        || game_mode.current == GameMode::Story
-    {
-        match level_id.old {
-            LevelID::Sonic1_GreenHillAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s1_green_hill_1,
-                    _ => settings.s1_green_hill_1,
-                }) && level_id.current == LevelID::Sonic1_GreenHillAct2
-            }
-            LevelID::Sonic1_GreenHillAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s1_green_hill_2,
-                    _ => settings.s1_green_hill_2,
-                }) && level_id.current == LevelID::Sonic1_GreenHillAct3
-            }
-            LevelID::Sonic1_GreenHillAct3 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s1_green_hill_3,
-                    _ => settings.s1_green_hill_3,
-                }) && level_id.current == LevelID::Sonic1_MarbleAct1
-            }
-            LevelID::Sonic1_MarbleAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s1_marble_1,
-                    _ => settings.s1_marble_1,
-                }) && level_id.current == LevelID::Sonic1_MarbleAct2
-            }
-            LevelID::Sonic1_MarbleAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s1_marble_2,
-                    _ => settings.s1_marble_2,
-                }) && level_id.current == LevelID::Sonic1_MarbleAct2
-            }
-            LevelID::Sonic1_MarbleAct3 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s1_marble_3,
-                    _ => settings.s1_marble_3,
-                }) && level_id.current == LevelID::Sonic1_SpringYardAct1
-            }
-            LevelID::Sonic1_SpringYardAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s1_spring_yard_1,
-                    _ => settings.s1_spring_yard_1,
-                }) && level_id.current == LevelID::Sonic1_SpringYardAct2
-            }
-            LevelID::Sonic1_SpringYardAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s1_spring_yard_2,
-                    _ => settings.s1_spring_yard_2,
-                }) && level_id.current == LevelID::Sonic1_SpringYardAct3
-            }
-            LevelID::Sonic1_SpringYardAct3 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s1_spring_yard_3,
-                    _ => settings.s1_spring_yard_3,
-                }) && level_id.current == LevelID::Sonic1_LabyrinthAct1
-            }
-            LevelID::Sonic1_LabyrinthAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s1_labyrinth_1,
-                    _ => settings.s1_labyrinth_1,
-                }) && level_id.current == LevelID::Sonic1_LabyrinthAct2
-            }
-            LevelID::Sonic1_LabyrinthAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s1_labyrinth_2,
-                    _ => settings.s1_labyrinth_2,
-                }) && level_id.current == LevelID::Sonic1_LabyrinthAct3
-            }
-            LevelID::Sonic1_LabyrinthAct3 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s1_labyrinth_3,
-                    _ => settings.s1_labyrinth_3,
-                }) && level_id.current == LevelID::Sonic1_StarLightAct1
-            }
-            LevelID::Sonic1_StarLightAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s1_star_light_1,
-                    _ => settings.s1_star_light_1,
-                }) && level_id.current == LevelID::Sonic1_StarLightAct2
-            }
-            LevelID::Sonic1_StarLightAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s1_star_light_2,
-                    _ => settings.s1_star_light_2,
-                }) && level_id.current == LevelID::Sonic1_StarLightAct3
-            }
-            LevelID::Sonic1_StarLightAct3 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s1_star_light_3,
-                    _ => settings.s1_star_light_3,
-                }) && level_id.current == LevelID::Sonic1_ScrapBrainAct1
-            }
-            LevelID::Sonic1_ScrapBrainAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s1_scrap_brain_1,
-                    _ => settings.s1_scrap_brain_1,
-                }) && level_id.current == LevelID::Sonic1_ScrapBrainAct2
-            }
-            LevelID::Sonic1_ScrapBrainAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s1_scrap_brain_2,
-                    _ => settings.s1_scrap_brain_2,
-                }) && level_id.current == LevelID::Sonic1_ScrapBrainAct3
-            }
-            LevelID::Sonic1_ScrapBrainAct3 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s1_scrap_brain_3,
-                    _ => settings.s1_scrap_brain_3,
-                }) && level_id.current == LevelID::Sonic1_FinalZone
-            }
-            LevelID::Sonic1_FinalZone => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s1_final_zone,
-                    _ => settings.s1_final_zone,
-                }) && level_id.current == LevelID::Sonic1_Ending
-            }
-            LevelID::Sonic2_EmeraldHillAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s2_emerald_hill_1,
-                    _ => settings.s2_emerald_hill_1,
-                }) && level_id.current == LevelID::Sonic2_EmeraldHillAct2
-            }
-            LevelID::Sonic2_EmeraldHillAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s2_emerald_hill_2,
-                    _ => settings.s2_emerald_hill_2,
-                }) && level_id.current == LevelID::Sonic2_ChemicalPlantAct1
-            }
-            LevelID::Sonic2_ChemicalPlantAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s2_chemical_plant_1,
-                    _ => settings.s2_chemical_plant_1,
-                }) && level_id.current == LevelID::Sonic2_ChemicalPlantAct2
-            }
-            LevelID::Sonic2_ChemicalPlantAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s2_chemical_plant_2,
-                    _ => settings.s2_chemical_plant_2,
-                }) && level_id.current == LevelID::Sonic2_AquaticRuinAct1
-            }
-            LevelID::Sonic2_AquaticRuinAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s2_aquatic_ruin_1,
-                    _ => settings.s2_aquatic_ruin_1,
-                }) && level_id.current == LevelID::Sonic2_AquaticRuinAct2
-            }
-            LevelID::Sonic2_AquaticRuinAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s2_aquatic_ruin_2,
-                    _ => settings.s2_aquatic_ruin_2,
-                }) && level_id.current == LevelID::Sonic2_CasinoNightAct1
-            }
-            LevelID::Sonic2_CasinoNightAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s2_casino_night_1,
-                    _ => settings.s2_casino_night_1,
-                }) && level_id.current == LevelID::Sonic2_CasinoNightAct2
-            }
-            LevelID::Sonic2_CasinoNightAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s2_casino_night_2,
-                    _ => settings.s2_casino_night_2,
-                }) && level_id.current == LevelID::Sonic2_HillTopAct1
-            }
-            LevelID::Sonic2_HillTopAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s2_hill_top_1,
-                    _ => settings.s2_hill_top_1,
-                }) && level_id.current == LevelID::Sonic2_HillTopAct2
-            }
-            LevelID::Sonic2_HillTopAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s2_hill_top_2,
-                    _ => settings.s2_hill_top_2,
-                }) && level_id.current == LevelID::Sonic2_MysticCaveAct1
-            }
-            LevelID::Sonic2_MysticCaveAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s2_mystic_cave_1,
-                    _ => settings.s2_mystic_cave_1,
-                }) && level_id.current == LevelID::Sonic2_MysticCaveAct2
-            }
-            LevelID::Sonic2_MysticCaveAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s2_mystic_cave_2,
-                    _ => settings.s2_mystic_cave_2,
-                }) && level_id.current == LevelID::Sonic2_OilOceanAct1
-            }
-            LevelID::Sonic2_OilOceanAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s2_oil_ocean_1,
-                    _ => settings.s2_oil_ocean_1,
-                }) && level_id.current == LevelID::Sonic2_OilOceanAct2
-            }
-            LevelID::Sonic2_OilOceanAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s2_oil_ocean_2,
-                    _ => settings.s2_oil_ocean_2,
-                }) && level_id.current == LevelID::Sonic2_MetropolisAct1
-            }
-            LevelID::Sonic2_MetropolisAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s2_metropolis_1,
-                    _ => settings.s2_metropolis_1,
-                }) && level_id.current == LevelID::Sonic2_MetropolisAct2
-            }
-            LevelID::Sonic2_MetropolisAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s2_metropolis_2,
-                    _ => settings.s2_metropolis_2,
-                }) && level_id.current == LevelID::Sonic2_MetropolisAct3
-            }
-            LevelID::Sonic2_MetropolisAct3 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s2_metropolis_3,
-                    _ => settings.s2_metropolis_3,
-                }) && level_id.current == LevelID::Sonic2_SkyChase
-            }
-            LevelID::Sonic2_SkyChase => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s2_sky_chase,
-                    _ => settings.s2_sky_chase,
-                }) && level_id.current == LevelID::Sonic2_WingFortress
-            }
-            LevelID::Sonic2_WingFortress => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s2_wing_fortress,
-                    _ => settings.s2_wing_fortress,
-                }) && level_id.current == LevelID::Sonic2_DeathEgg
-            }
-            LevelID::Sonic2_DeathEgg => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s2_death_egg,
-                    _ => settings.s2_death_egg,
-                }) && level_id.current == LevelID::Sonic2_Ending
-            }
-            LevelID::Sonic3_AngelIslandAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s3_angel_island_1,
-                    _ => settings.s3_angel_island_1,
-                }) && level_id.current == LevelID::Sonic3_AngelIslandAct2
-            }
-            LevelID::Sonic3_AngelIslandAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s3_angel_island_2,
-                    _ => settings.s3_angel_island_2,
-                }) && level_id.current == LevelID::Sonic3_HydrocityAct1
-            }
-            LevelID::Sonic3_HydrocityAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s3_hydrocity_1,
-                    _ => settings.s3_hydrocity_1,
-                }) && level_id.current == LevelID::Sonic3_HydrocityAct2
-            }
-            LevelID::Sonic3_HydrocityAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s3_hydricity_2,
-                    _ => settings.s3_hydrocity_2,
-                }) && level_id.current == LevelID::Sonic3_MarbleGardenAct1
-            }
-            LevelID::Sonic3_MarbleGardenAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s3_marble_garden_1,
-                    _ => settings.s3_marble_garden_1,
-                }) && level_id.current == LevelID::Sonic3_MarbleGardenAct2
-            }
-            LevelID::Sonic3_MarbleGardenAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s3_marble_garden_2,
-                    _ => settings.s3_marble_garden_2,
-                }) && level_id.current == LevelID::Sonic3_CarnivalNightAct1
-            }
-            LevelID::Sonic3_CarnivalNightAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s3_carnival_night_1,
-                    _ => settings.s3_carnival_night_1,
-                }) && level_id.current == LevelID::Sonic3_CarnivalNightAct2
+    match game_mode.current {
+        GameMode::Classic | GameMode::Mirror | GameMode::Anniversary | GameMode::Story => {
+            if settings.emerald_split
+                && watchers.game.pair.is_some_and(|val| val.unchanged())
+                && [
+                    &watchers.chaos_emeralds,
+                    &watchers.super_emeralds,
+                    &watchers.time_stones,
+                ]
+                .iter()
+                .any(|watcher| watcher.pair.is_some_and(|val| val.current > val.old))
+            {
+                return true;
             }
-            LevelID::Sonic3_CarnivalNightAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s3_carnival_night_2,
-                    _ => settings.s3_carnival_night_2,
-                }) && level_id.current == LevelID::Sonic3_IceCapAct1
-            }
-            LevelID::Sonic3_IceCapAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s3_ice_cap_1,
-                    _ => settings.s3_ice_cap_1,
-                }) && level_id.current == LevelID::Sonic3_IceCapAct2
-            }
-            LevelID::Sonic3_IceCapAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s3_ice_cap_2,
-                    _ => settings.s3_ice_cap_2,
-                }) && level_id.current == LevelID::Sonic3_LaunchBaseAct1
-            }
-            LevelID::Sonic3_LaunchBaseAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s3_launch_base_1,
-                    _ => settings.s3_launch_base_1,
-                }) && level_id.current == LevelID::Sonic3_LaunchBaseAct2
-            }
-            LevelID::Sonic3_LaunchBaseAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s3_launch_base_2,
-                    _ => settings.s3_launch_base_2,
-                }) && level_id.current == LevelID::Sonic3_MushroomHillAct1
-            }
-            LevelID::Sonic3_MushroomHillAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s3_mushroom_hill_1,
-                    _ => settings.s3_mushroom_hill_1,
-                }) && (level_id.current == LevelID::Sonic3_MushroomHillAct2
-                    || level_id.current == LevelID::Sonic3_HiddenPalace)
-            }
-            LevelID::Sonic3_MushroomHillAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s3_mushroom_hill_2,
-                    _ => settings.s3_mushroom_hill_2,
-                }) && (level_id.current == LevelID::Sonic3_FlyingBatteryAct1
-                    || level_id.current == LevelID::Sonic3_HiddenPalace)
-            }
-            LevelID::Sonic3_FlyingBatteryAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s3_flying_battery_1,
-                    _ => settings.s3_flying_battery_1,
-                }) && (level_id.current == LevelID::Sonic3_FlyingBatteryAct2
-                    || level_id.current == LevelID::Sonic3_HiddenPalace)
-            }
-            LevelID::Sonic3_FlyingBatteryAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s3_flying_battery_2,
-                    _ => settings.s3_flying_battery_2,
-                }) && (level_id.current == LevelID::Sonic3_SandopolisAct1
-                    || level_id.current == LevelID::Sonic3_HiddenPalace)
-            }
-            LevelID::Sonic3_SandopolisAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s3_sandopolis_1,
-                    _ => settings.s3_sandopolis_1,
-                }) && (level_id.current == LevelID::Sonic3_SandopolisAct2
-                    || level_id.current == LevelID::Sonic3_HiddenPalace)
-            }
-            LevelID::Sonic3_SandopolisAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s3_sandopolis_2,
-                    _ => settings.s3_sandopolis_2,
-                }) && (level_id.current == LevelID::Sonic3_LavaReefAct1
-                    || level_id.current == LevelID::Sonic3_HiddenPalace)
-            }
-            LevelID::Sonic3_LavaReefAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s3_lava_reef_1,
-                    _ => settings.s3_lava_reef_1,
-                }) && (level_id.current == LevelID::Sonic3_LavaReefAct2
-                    || level_id.current == LevelID::Sonic3_HiddenPalace)
-            }
-            LevelID::Sonic3_LavaReefAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s3_lava_reef_2,
-                    _ => settings.s3_lava_reef_2,
-                }) && level_id.current == LevelID::Sonic3_HiddenPalace
-            }
-            LevelID::Sonic3_HiddenPalace => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s3_hidden_palace,
-                    _ => settings.s3_hidden_palace,
-                }) && level_id.current == LevelID::Sonic3_SkySanctuary
-            }
-            LevelID::Sonic3_SkySanctuary => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s3_sky_sanctuary,
-                    _ => settings.s3_sky_sanctuary,
-                }) && (level_id.current == LevelID::Sonic3_DeathEggAct1
-                    || level_id.current == LevelID::Sonic3_Ending)
-            }
-            LevelID::Sonic3_DeathEggAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s3_death_egg_1,
-                    _ => settings.s3_death_egg_1,
-                }) && level_id.current == LevelID::Sonic3_DeathEggAct2
-            }
-            LevelID::Sonic3_DeathEggAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s3_death_egg_2,
-                    _ => settings.s3_death_egg_2,
-                }) && (level_id.current == LevelID::Sonic3_Doomsday
-                    || level_id.current == LevelID::Sonic3_Ending)
-            }
-            LevelID::Sonic3_Doomsday => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_s3_doomsday,
-                    _ => settings.s3_doomsday,
-                }) && level_id.current == LevelID::Sonic3_Ending
-            }
-            LevelID::SonicCD_PalmtreePanicAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_scd_palmtree_panic_1,
-                    _ => settings.scd_palmtree_panic_1,
-                }) && level_id.current == LevelID::SonicCD_PalmtreePanicAct2
-            }
-            LevelID::SonicCD_PalmtreePanicAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_scd_palmtree_panic_2,
-                    _ => settings.scd_palmtree_panic_2,
-                }) && level_id.current == LevelID::SonicCD_PalmtreePanicAct3
-            }
-            LevelID::SonicCD_PalmtreePanicAct3 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_scd_palmtree_panic_3,
-                    _ => settings.scd_palmtree_panic_3,
-                }) && level_id.current == LevelID::SonicCD_CollisionChaosAct1
-            }
-            LevelID::SonicCD_CollisionChaosAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_scd_collision_chaos_1,
-                    _ => settings.scd_collision_chaos_1,
-                }) && level_id.current == LevelID::SonicCD_CollisionChaosAct2
-            }
-            LevelID::SonicCD_CollisionChaosAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_scd_collision_chaos_2,
-                    _ => settings.scd_collision_chaos_2,
-                }) && level_id.current == LevelID::SonicCD_CollisionChaosAct3
-            }
-            LevelID::SonicCD_CollisionChaosAct3 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_scd_collision_chaos_3,
-                    _ => settings.scd_collision_chaos_3,
-                }) && level_id.current == LevelID::SonicCD_TidalTempestAct1
-            }
-            LevelID::SonicCD_TidalTempestAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_scd_tidal_tempest_1,
-                    _ => settings.scd_tidal_tempest_1,
-                }) && level_id.current == LevelID::SonicCD_TidalTempestAct2
-            }
-            LevelID::SonicCD_TidalTempestAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_scd_tidal_tempest_2,
-                    _ => settings.scd_tidal_tempest_2,
-                }) && level_id.current == LevelID::SonicCD_TidalTempestAct3
-            }
-            LevelID::SonicCD_TidalTempestAct3 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_scd_tidal_tempest_3,
-                    _ => settings.scd_tidal_tempest_3,
-                }) && level_id.current == LevelID::SonicCD_QuartzQuadrantAct1
-            }
-            LevelID::SonicCD_QuartzQuadrantAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_scd_quartz_quadrant_1,
-                    _ => settings.scd_quartz_quadrant_1,
-                }) && level_id.current == LevelID::SonicCD_QuartzQuadrantAct2
-            }
-            LevelID::SonicCD_QuartzQuadrantAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_scd_quartz_quadrant_2,
-                    _ => settings.scd_quartz_quadrant_2,
-                }) && level_id.current == LevelID::SonicCD_QuartzQuadrantAct3
-            }
-            LevelID::SonicCD_QuartzQuadrantAct3 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_scd_quartz_quadrant_3,
-                    _ => settings.scd_quartz_quadrant_3,
-                }) && level_id.current == LevelID::SonicCD_WackyWorkbenchAct1
-            }
-            LevelID::SonicCD_WackyWorkbenchAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_scd_wacky_workbench_1,
-                    _ => settings.scd_wacky_workbench_1,
-                }) && level_id.current == LevelID::SonicCD_WackyWorkbenchAct2
-            }
-            LevelID::SonicCD_WackyWorkbenchAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_scd_wacky_workbench_2,
-                    _ => settings.scd_wacky_workbench_2,
-                }) && level_id.current == LevelID::SonicCD_WackyWorkbenchAct3
-            }
-            LevelID::SonicCD_WackyWorkbenchAct3 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_scd_wacky_workbench_3,
-                    _ => settings.scd_wacky_workbench_3,
-                }) && level_id.current == LevelID::SonicCD_StardustSpeedwayAct1
-            }
-            LevelID::SonicCD_StardustSpeedwayAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_scd_stardust_speedway_1,
-                    _ => settings.scd_stardust_speedway_1,
-                }) && level_id.current == LevelID::SonicCD_StardustSpeedwayAct2
-            }
-            LevelID::SonicCD_StardustSpeedwayAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_scd_stardust_speedway_2,
-                    _ => settings.scd_stardust_speedway_2,
-                }) && level_id.current == LevelID::SonicCD_StardustSpeedwayAct3
-            }
-            LevelID::SonicCD_StardustSpeedwayAct3 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_scd_stardust_speedway_3,
-                    _ => settings.scd_stardust_speedway_3,
-                }) && level_id.current == LevelID::SonicCD_MetallicMadnessAct1
-            }
-            LevelID::SonicCD_MetallicMadnessAct1 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_scd_metallic_madness_1,
-                    _ => settings.scd_metallic_madness_1,
-                }) && level_id.current == LevelID::SonicCD_MetallicMadnessAct2
-            }
-            LevelID::SonicCD_MetallicMadnessAct2 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_scd_metallic_madness_2,
-                    _ => settings.scd_metallic_madness_2,
-                }) && level_id.current == LevelID::SonicCD_MetallicMadnessAct3
-            }
-            LevelID::SonicCD_MetallicMadnessAct3 => {
-                (match game_mode.current {
-                    GameMode::Story => settings.story_scd_metallic_madness_3,
-                    _ => settings.scd_metallic_madness_3,
-                }) && level_id.current == LevelID::SonicCD_Ending
+
+            // One split per game, either on its ending or when the run moves on to the next
+            // game without the ending having been seen
+            if game_mode.current == GameMode::Story && settings.story_game_splits {
+                return last_act.changed() && last_act.current.is_ending()
+                    || watchers
+                        .game
+                        .pair
+                        .is_some_and(|val| val.changed() && val.old.story_segment().is_some())
+                        && watchers.ending_reached.pair.is_some_and(|val| !val.old);
             }
-            _ => false,
+
+            let character = match &watchers.character.pair {
+                Some(x) => x.current,
+                _ => Character::SonicAndTails,
+            };
+
+            let granularity = split_granularity(watchers, settings);
+
+            CharacterEdge::find(last_act.old, character)
+                .map(|val| &val.edge)
+                .or_else(|| RouteEdge::find(last_act.old))
+                .is_some_and(|edge| {
+                    edge.to.contains(&last_act.current)
+                        && edge.splits_at(last_act.current, granularity)
+                        && edge.is_enabled(settings, game_mode.current == GameMode::Story)
+                })
+                && (!settings.scd_good_future
+                    || game_mode.current == GameMode::Story
+                    || watchers
+                        .game
+                        .pair
+                        .is_some_and(|val| val.current != Game::SonicCD)
+                    // Without the Good Future flags this setting can't hold splits back
+                    || watchers.good_future.pair.is_none_or(|val| val.old))
+        }
+        // Each boss is fought in its own act, so moving on to the next one means
+        // the previous boss has been defeated. The last one is followed by the results
+        GameMode::BossRush => {
+            settings.boss_rush_boss
+                && level_id.changed()
+                && level_id.old.is_act()
+                && level_id.current.is_act()
+                || settings.boss_rush_results
+                    && watchers
+                        .game_status
+                        .pair
+                        .is_some_and(|val| val.changed_to(&GameStatus::Results))
+        }
+        GameMode::Mission => false,
+        GameMode::BlueSpheresClassic | GameMode::BlueSpheresNew => {
+            let Some(stage) = &watchers.blue_spheres_stage.pair else {
+                return false;
+            };
+
+            // The stage number only goes up by one once the previous stage is cleared
+            let cleared = stage.old;
+
+            settings.blue_spheres_stage
+                && cleared != 0
+                && stage.current == cleared + 1
+                && match settings.blue_spheres_stop_stage() {
+                    Some(stop) if cleared >= stop => cleared == stop,
+                    _ => cleared % settings.blue_spheres_split_interval() == 0,
+                }
         }
-    } else {
-        false
     }
 }
 
-fn reset(_watchers: &Watchers, _settings: &Settings) -> bool {
-    false
+/// Splits to skip around the one `split()` fired, one for each enabled act that the
+/// selected character never visits. Only the character edge itself skips any, so splits
+/// on emeralds never do.
+fn skipped_splits(watchers: &Watchers, settings: &Settings) -> SkippedSplits {
+    let (Some(game_mode), Some(last_act), Some(character)) = (
+        &watchers.game_mode.pair,
+        &watchers.last_act.pair,
+        &watchers.character.pair,
+    ) else {
+        return SkippedSplits::default();
+    };
+
+    if !last_act.changed() || game_mode.current == GameMode::Story && settings.story_game_splits {
+        return SkippedSplits::default();
+    }
+
+    let granularity = split_granularity(watchers, settings);
+
+    CharacterEdge::find(last_act.old, character.current)
+        .filter(|val| val.edge.to.contains(&last_act.current))
+        .map_or(SkippedSplits::default(), |val| {
+            val.skipped_splits(last_act.current, granularity, |edge| {
+                edge.is_enabled(settings, game_mode.current == GameMode::Story)
+            })
+        })
 }
 
-fn is_loading(watchers: &Watchers, _settings: &Settings) -> Option<bool> {
-    Some(watchers.is_in_time_bonus.pair?.current)
+fn split_granularity(watchers: &Watchers, settings: &Settings) -> SplitGranularity {
+    settings.split_granularity(
+        watchers.game.pair.map_or(Game::None, |val| val.current),
+        watchers
+            .game_mode
+            .pair
+            .is_some_and(|val| val.current == GameMode::Story),
+    )
 }
 
-fn game_time(
-    _watchers: &Watchers,
-    _settings: &Settings,
-    _addresses: &Addresses,
-) -> Option<Duration> {
-    None
+fn reset(watchers: &Watchers, settings: &Settings) -> bool {
+    let Some(game_status) = &watchers.game_status.pair else {
+        return false;
+    };
+    let Some(game) = &watchers.game.pair else {
+        return false;
+    };
+    let Some(game_mode) = &watchers.game_mode.pair else {
+        return false;
+    };
+    let Some(level_id) = &watchers.act_id.pair else {
+        return false;
+    };
+
+    let back_to_main_menu = game_status.changed() && game_status.current.is_menu();
+
+    // In Story mode every game goes through its own title screen when the run hands
+    // off to the next one, so only quitting to the main menu is treated as a reset
+    if game_mode.old == GameMode::Story {
+        return settings.story_reset && back_to_main_menu;
+    }
+
+    let back_to_title_screen = game.unchanged()
+        && level_id.changed()
+        && matches!(
+            level_id.current,
+            LevelID::Sonic1_TitleScreen
+                | LevelID::Sonic2_TitleScreen
+                | LevelID::SonicCD_TitleScreen
+                | LevelID::Sonic3_TitleScreen
+                | LevelID::Sonic3_SaveSelect
+        );
+
+    (back_to_main_menu || back_to_title_screen)
+        && match game.old {
+            Game::Sonic1 => settings.s1_reset,
+            Game::SonicCD => settings.scd_reset,
+            Game::Sonic2 => settings.s2_reset,
+            Game::Sonic3 => settings.s3_reset,
+            Game::GameGear | Game::None => false,
+        }
+}
+
+fn is_loading(watchers: &Watchers, settings: &Settings) -> Option<bool> {
+    if settings.igt {
+        return Some(true);
+    }
+    Some(
+        watchers.is_in_time_bonus.pair?.current
+            || settings.story_cutscenes && watchers.story_cutscene
+            || settings.story_loads && watchers.story_game_switch && !watchers.story_cutscene
+            || settings.loading_screens
+                && watchers
+                    .game_status
+                    .pair
+                    .is_some_and(|val| val.current == GameStatus::Loading),
+    )
+}
+
+fn game_time(watchers: &Watchers, settings: &Settings, _addresses: &Addresses) -> Option<Duration> {
+    if !settings.igt {
+        return None;
+    }
+    let frames = watchers.igt.total_frames();
+    Some(Duration::new(
+        (frames / 60) as i64,
+        ((frames % 60) * 1_000_000_000 / 60) as i32,
+    ))
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -1418,16 +1670,157 @@ enum GameStatus {
     MainMenu,
     RetroEngine,
     GameGear,
+    /// Full-motion video player, used for the game intros and Story mode cutscenes.
+    Movie,
+    Museum,
+    Loading,
+    /// Results tallies shown after a mission or a Boss Rush run.
+    Results,
+}
+
+impl GameStatus {
+    /// Front-end game modes of the Origins launcher, by demangled RTTI class name. Only the
+    /// main menu, RetroEngine and Game Gear classes are known from earlier builds, so the
+    /// others are looked up in the executable on attach.
+    const CLASSES: &'static [(&'static str, GameStatus)] = &[
+        ("app::game::GameModeMainMenu", GameStatus::MainMenu),
+        ("app::game::GameModeRetroEngine", GameStatus::RetroEngine),
+        ("app::game::GameModeGameGear", GameStatus::GameGear),
+        ("app::game::GameModeMovie", GameStatus::Movie),
+        ("app::game::GameModeMuseum", GameStatus::Museum),
+        ("app::game::GameModeLoading", GameStatus::Loading),
+        ("app::game::GameModeResult", GameStatus::Results),
+        ("app::game::GameModeMissionResult", GameStatus::Results),
+    ];
+
+    /// Logs the classes of `CLASSES` the executable does not declare. A misnamed class
+    /// would otherwise just never be detected, silently disabling what depends on it.
+    fn report_missing_classes(process: &Process, rtti: &Rtti) {
+        let mut declared = [false; Self::CLASSES.len()];
+        rtti.for_each_class(process, |class| {
+            for (declared, (name, _)) in declared.iter_mut().zip(Self::CLASSES) {
+                *declared |= class == *name;
+            }
+        });
+
+        for ((name, _), _) in Self::CLASSES
+            .iter()
+            .zip(declared)
+            .filter(|&(_, declared)| !declared)
+        {
+            asr::print_limited::<160>(&format_args!(
+                "Front-end mode {name}: class not found in the executable, it is never detected"
+            ));
+        }
+    }
+
+    fn from_class(class: Demangled<'_>) -> Option<Self> {
+        Self::CLASSES
+            .iter()
+            .find(|(name, _)| class == *name)
+            .map(|&(_, status)| status)
+    }
+
+    /// Whether the player is browsing the launcher rather than playing.
+    const fn is_menu(self) -> bool {
+        matches!(self, GameStatus::MainMenu | GameStatus::Museum)
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
-enum Game {
+#[allow(clippy::enum_variant_names)]
+pub enum Game {
     None,
     Sonic1,
     SonicCD,
     Sonic2,
     Sonic3,
-    //GameGear,
+    GameGear,
+}
+
+impl Game {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Game::None => "Main Menu",
+            Game::Sonic1 => "Sonic 1",
+            Game::SonicCD => "Sonic CD",
+            Game::Sonic2 => "Sonic 2",
+            Game::Sonic3 => "Sonic 3 & Knuckles",
+            Game::GameGear => "Game Gear",
+        }
+    }
+
+    /// Position of the game within a Story mode run.
+    const fn story_segment(self) -> Option<&'static str> {
+        match self {
+            Game::Sonic1 => Some("Sonic 1 (1/4)"),
+            Game::SonicCD => Some("Sonic CD (2/4)"),
+            Game::Sonic2 => Some("Sonic 2 (3/4)"),
+            Game::Sonic3 => Some("Sonic 3 & Knuckles (4/4)"),
+            _ => None,
+        }
+    }
+}
+
+/// The in-game stage timer, as shown on the HUD.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct StageTime {
+    pub minutes: u8,
+    pub seconds: u8,
+    pub frames: u8,
+}
+
+impl StageTime {
+    /// RSDKv4 only exposes hundredths of a second, derived from a 60 fps frame counter.
+    pub const fn from_centiseconds(minutes: u8, seconds: u8, centiseconds: u8) -> Self {
+        Self {
+            minutes,
+            seconds,
+            frames: ((centiseconds as u16 * 3).div_ceil(5)) as u8,
+        }
+    }
+
+    pub const fn total_frames(&self) -> u64 {
+        (self.minutes as u64 * 60 + self.seconds as u64) * 60 + self.frames as u64
+    }
+}
+
+/// In-game time of a whole run, pieced together from the stage timer of each act.
+///
+/// The stage timer goes back to zero when a new act starts, but also when the player
+/// dies or restarts the act. Only an act that has been left for a different one gets
+/// its time banked, so the time lost to a death or a restart is never added.
+#[derive(Default)]
+struct IgtTracker {
+    banked_frames: u64,
+    act: Option<LevelID>,
+    act_frames: u64,
+}
+
+impl IgtTracker {
+    fn update(&mut self, act_id: LevelID, game_status: GameStatus, stage_time: Option<StageTime>) {
+        // Menus, unmapped levels and failed reads keep the last time of the current act
+        let Some(stage_time) = stage_time else {
+            return;
+        };
+        if game_status != GameStatus::RetroEngine || !act_id.is_act() {
+            return;
+        }
+
+        if self.act.is_some_and(|act| act != act_id) {
+            self.banked_frames += self.act_frames;
+        }
+        self.act = Some(act_id);
+        self.act_frames = stage_time.total_frames();
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    const fn total_frames(&self) -> u64 {
+        self.banked_frames + self.act_frames
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -1442,6 +1835,21 @@ pub enum GameMode {
     BlueSpheresNew,
 }
 
+impl GameMode {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            GameMode::Classic => "Classic",
+            GameMode::Anniversary => "Anniversary",
+            GameMode::BossRush => "Boss Rush",
+            GameMode::Mirror => "Mirror",
+            GameMode::Mission => "Mission",
+            GameMode::Story => "Story",
+            GameMode::BlueSpheresClassic => "Blue Spheres Classic",
+            GameMode::BlueSpheresNew => "Blue Spheres New",
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 #[allow(non_camel_case_types)]
 pub enum LevelID {
@@ -1542,3 +1950,229 @@ pub enum LevelID {
     SonicCD_MetallicMadnessAct3,
     SonicCD_Ending,
 }
+
+impl LevelID {
+    /// Every variant, in declaration order.
+    pub const ALL: &'static [LevelID] = &[
+        LevelID::MainMenu,
+        LevelID::Unknown,
+        LevelID::Sonic1_TitleScreen,
+        LevelID::Sonic1_GreenHillAct1,
+        LevelID::Sonic1_GreenHillAct2,
+        LevelID::Sonic1_GreenHillAct3,
+        LevelID::Sonic1_MarbleAct1,
+        LevelID::Sonic1_MarbleAct2,
+        LevelID::Sonic1_MarbleAct3,
+        LevelID::Sonic1_SpringYardAct1,
+        LevelID::Sonic1_SpringYardAct2,
+        LevelID::Sonic1_SpringYardAct3,
+        LevelID::Sonic1_LabyrinthAct1,
+        LevelID::Sonic1_LabyrinthAct2,
+        LevelID::Sonic1_LabyrinthAct3,
+        LevelID::Sonic1_StarLightAct1,
+        LevelID::Sonic1_StarLightAct2,
+        LevelID::Sonic1_StarLightAct3,
+        LevelID::Sonic1_ScrapBrainAct1,
+        LevelID::Sonic1_ScrapBrainAct2,
+        LevelID::Sonic1_ScrapBrainAct3,
+        LevelID::Sonic1_FinalZone,
+        LevelID::Sonic1_Ending,
+        LevelID::Sonic2_TitleScreen,
+        LevelID::Sonic2_EmeraldHillAct1,
+        LevelID::Sonic2_EmeraldHillAct2,
+        LevelID::Sonic2_ChemicalPlantAct1,
+        LevelID::Sonic2_ChemicalPlantAct2,
+        LevelID::Sonic2_AquaticRuinAct1,
+        LevelID::Sonic2_AquaticRuinAct2,
+        LevelID::Sonic2_CasinoNightAct1,
+        LevelID::Sonic2_CasinoNightAct2,
+        LevelID::Sonic2_HillTopAct1,
+        LevelID::Sonic2_HillTopAct2,
+        LevelID::Sonic2_MysticCaveAct1,
+        LevelID::Sonic2_MysticCaveAct2,
+        LevelID::Sonic2_OilOceanAct1,
+        LevelID::Sonic2_OilOceanAct2,
+        LevelID::Sonic2_MetropolisAct1,
+        LevelID::Sonic2_MetropolisAct2,
+        LevelID::Sonic2_MetropolisAct3,
+        LevelID::Sonic2_SkyChase,
+        LevelID::Sonic2_WingFortress,
+        LevelID::Sonic2_DeathEgg,
+        LevelID::Sonic2_Ending,
+        LevelID::Sonic3_TitleScreen,
+        LevelID::Sonic3_SaveSelect,
+        LevelID::Sonic3_AngelIslandAct1,
+        LevelID::Sonic3_AngelIslandAct2,
+        LevelID::Sonic3_HydrocityAct1,
+        LevelID::Sonic3_HydrocityAct2,
+        LevelID::Sonic3_MarbleGardenAct1,
+        LevelID::Sonic3_MarbleGardenAct2,
+        LevelID::Sonic3_CarnivalNightAct1,
+        LevelID::Sonic3_CarnivalNightAct2,
+        LevelID::Sonic3_IceCapAct1,
+        LevelID::Sonic3_IceCapAct2,
+        LevelID::Sonic3_LaunchBaseAct1,
+        LevelID::Sonic3_LaunchBaseAct2,
+        LevelID::Sonic3_MushroomHillAct1,
+        LevelID::Sonic3_MushroomHillAct2,
+        LevelID::Sonic3_FlyingBatteryAct1,
+        LevelID::Sonic3_FlyingBatteryAct2,
+        LevelID::Sonic3_SandopolisAct1,
+        LevelID::Sonic3_SandopolisAct2,
+        LevelID::Sonic3_LavaReefAct1,
+        LevelID::Sonic3_LavaReefAct2,
+        LevelID::Sonic3_HiddenPalace,
+        LevelID::Sonic3_SkySanctuary,
+        LevelID::Sonic3_DeathEggAct1,
+        LevelID::Sonic3_DeathEggAct2,
+        LevelID::Sonic3_Doomsday,
+        LevelID::Sonic3_Ending,
+        LevelID::SonicCD_TitleScreen,
+        LevelID::SonicCD_PalmtreePanicAct1,
+        LevelID::SonicCD_PalmtreePanicAct2,
+        LevelID::SonicCD_PalmtreePanicAct3,
+        LevelID::SonicCD_CollisionChaosAct1,
+        LevelID::SonicCD_CollisionChaosAct2,
+        LevelID::SonicCD_CollisionChaosAct3,
+        LevelID::SonicCD_TidalTempestAct1,
+        LevelID::SonicCD_TidalTempestAct2,
+        LevelID::SonicCD_TidalTempestAct3,
+        LevelID::SonicCD_QuartzQuadrantAct1,
+        LevelID::SonicCD_QuartzQuadrantAct2,
+        LevelID::SonicCD_QuartzQuadrantAct3,
+        LevelID::SonicCD_WackyWorkbenchAct1,
+        LevelID::SonicCD_WackyWorkbenchAct2,
+        LevelID::SonicCD_WackyWorkbenchAct3,
+        LevelID::SonicCD_StardustSpeedwayAct1,
+        LevelID::SonicCD_StardustSpeedwayAct2,
+        LevelID::SonicCD_StardustSpeedwayAct3,
+        LevelID::SonicCD_MetallicMadnessAct1,
+        LevelID::SonicCD_MetallicMadnessAct2,
+        LevelID::SonicCD_MetallicMadnessAct3,
+        LevelID::SonicCD_Ending,
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const fn time(minutes: u8, seconds: u8, frames: u8) -> Option<StageTime> {
+        Some(StageTime {
+            minutes,
+            seconds,
+            frames,
+        })
+    }
+
+    #[test]
+    fn igt_banks_an_act_once_the_next_one_starts() {
+        let mut igt = IgtTracker::default();
+        igt.update(
+            LevelID::Sonic1_GreenHillAct1,
+            GameStatus::RetroEngine,
+            time(0, 30, 0),
+        );
+        igt.update(
+            LevelID::Sonic1_GreenHillAct1,
+            GameStatus::RetroEngine,
+            time(0, 45, 0),
+        );
+        assert_eq!(igt.total_frames(), 45 * 60);
+
+        igt.update(
+            LevelID::Sonic1_GreenHillAct2,
+            GameStatus::RetroEngine,
+            time(0, 0, 0),
+        );
+        assert_eq!(igt.total_frames(), 45 * 60);
+        igt.update(
+            LevelID::Sonic1_GreenHillAct2,
+            GameStatus::RetroEngine,
+            time(1, 0, 30),
+        );
+        assert_eq!(igt.total_frames(), (45 + 60) * 60 + 30);
+    }
+
+    #[test]
+    fn igt_drops_the_time_lost_to_a_death_or_restart() {
+        let mut igt = IgtTracker::default();
+        igt.update(
+            LevelID::Sonic2_ChemicalPlantAct2,
+            GameStatus::RetroEngine,
+            time(2, 0, 0),
+        );
+        igt.update(
+            LevelID::Sonic2_ChemicalPlantAct2,
+            GameStatus::RetroEngine,
+            time(0, 0, 0),
+        );
+        igt.update(
+            LevelID::Sonic2_ChemicalPlantAct2,
+            GameStatus::RetroEngine,
+            time(0, 10, 0),
+        );
+        assert_eq!(igt.total_frames(), 10 * 60);
+    }
+
+    #[test]
+    fn igt_ignores_failed_reads_and_other_screens() {
+        let mut igt = IgtTracker::default();
+        igt.update(
+            LevelID::SonicCD_PalmtreePanicAct1,
+            GameStatus::RetroEngine,
+            time(0, 50, 0),
+        );
+        igt.update(
+            LevelID::SonicCD_PalmtreePanicAct2,
+            GameStatus::RetroEngine,
+            None,
+        );
+        igt.update(
+            LevelID::SonicCD_PalmtreePanicAct2,
+            GameStatus::Loading,
+            time(0, 0, 0),
+        );
+        igt.update(LevelID::MainMenu, GameStatus::MainMenu, time(9, 0, 0));
+        assert_eq!(igt.total_frames(), 50 * 60);
+    }
+
+    #[test]
+    fn igt_keeps_timing_an_act_through_an_unmapped_level() {
+        let mut igt = IgtTracker::default();
+        igt.update(
+            LevelID::Sonic3_IceCapAct1,
+            GameStatus::RetroEngine,
+            time(1, 0, 0),
+        );
+        igt.update(LevelID::Unknown, GameStatus::RetroEngine, time(0, 0, 0));
+        igt.update(
+            LevelID::Sonic3_IceCapAct1,
+            GameStatus::RetroEngine,
+            time(1, 0, 1),
+        );
+        assert_eq!(igt.total_frames(), 60 * 60 + 1);
+    }
+
+    #[test]
+    fn igt_starts_over_after_a_reset() {
+        let mut igt = IgtTracker::default();
+        igt.update(
+            LevelID::Sonic1_MarbleAct1,
+            GameStatus::RetroEngine,
+            time(0, 20, 0),
+        );
+        igt.update(
+            LevelID::Sonic1_MarbleAct2,
+            GameStatus::RetroEngine,
+            time(0, 5, 0),
+        );
+        igt.reset();
+        igt.update(
+            LevelID::Sonic1_GreenHillAct1,
+            GameStatus::RetroEngine,
+            time(0, 1, 0),
+        );
+        assert_eq!(igt.total_frames(), 60);
+    }
+}
@@ -0,0 +1,317 @@
+use crate::StageTime;
+use asr::{signature::Signature, string::ArrayCString, Address, Process};
+
+/// Size of the engine's global variable tables.
+const GLOBAL_VARIABLE_COUNT: u64 = 0x100;
+/// Length of each entry in the global variable name table.
+const GLOBAL_VARIABLE_NAME_LENGTH: usize = 0x20;
+
+/// `stage.*` variables exposed to the game scripts by the engine itself, resolved through the
+/// script interpreter's jump table.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum StageVariable {
+    State,
+    ListPos,
+    MilliSeconds,
+    Seconds,
+    Minutes,
+}
+
+impl StageVariable {
+    const fn name(self) -> &'static str {
+        match self {
+            StageVariable::State => "stage.state",
+            StageVariable::ListPos => "stage.listPos",
+            StageVariable::MilliSeconds => "stage.milliSeconds",
+            StageVariable::Seconds => "stage.seconds",
+            StageVariable::Minutes => "stage.minutes",
+        }
+    }
+}
+
+/// The RetroEngine v4 builds compiled into the Origins executable. Sonic 1 and Sonic 2
+/// share one, Sonic CD has its own.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum EngineBuild {
+    Sonic1And2,
+    SonicCD,
+}
+
+impl EngineBuild {
+    /// Jump table case of the script opcode reading `global` variables.
+    const fn global_case(self) -> u64 {
+        match self {
+            EngineBuild::Sonic1And2 => 17,
+            EngineBuild::SonicCD => 11,
+        }
+    }
+
+    /// Offset, within each jump table case, of the displacement to the variable.
+    const fn case_offset(self) -> u64 {
+        match self {
+            EngineBuild::Sonic1And2 => 39,
+            EngineBuild::SonicCD => 46,
+        }
+    }
+
+    const fn stage_variable_case(self, variable: StageVariable) -> u64 {
+        let stage_state = match self {
+            EngineBuild::Sonic1And2 => 120,
+            EngineBuild::SonicCD => 118,
+        };
+
+        stage_state
+            + match variable {
+                StageVariable::State => 0,
+                StageVariable::ListPos => 2,
+                StageVariable::MilliSeconds => 4,
+                StageVariable::Seconds => 5,
+                StageVariable::Minutes => 6,
+            }
+    }
+}
+
+/// Resolves the addresses of a RetroEngine v4 instance: engine script variables, global
+/// variables by name, and the stage's object memory.
+pub struct Resolver<'a> {
+    process: &'a Process,
+    main_module_range: (Address, u64),
+    owner: &'static str,
+    build: EngineBuild,
+    jump_table: Address,
+    object_memory: Address,
+    global_variables: Address,
+    global_variable_names: Option<Address>,
+}
+
+impl<'a> Resolver<'a> {
+    pub fn new(
+        process: &'a Process,
+        main_module_range: (Address, u64),
+        owner: &'static str,
+        build: EngineBuild,
+    ) -> Option<Self> {
+        let jump_table = {
+            let scanned = match build {
+                EngineBuild::Sonic1And2 => {
+                    const SIG: Signature<27> = Signature::new(
+                        "3D F9 00 00 00 0F 87 ?? ?? ?? ?? 41 8B 8C 84 ?? ?? ?? ?? 49 03 CC FF ?? 41 8B 84",
+                    );
+                    SIG.scan_process_range(process, main_module_range)
+                }
+                EngineBuild::SonicCD => {
+                    const SIG: Signature<27> = Signature::new(
+                        "3D E4 00 00 00 0F 87 ?? ?? ?? ?? 41 8B 8C 84 ?? ?? ?? ?? 49 03 CC FF ?? 41 8B 84",
+                    );
+                    SIG.scan_process_range(process, main_module_range)
+                }
+            }
+            .or_else(|| crate::address_error(owner, "jump_table", "signature scan"))?
+                + 15;
+            main_module_range.0 + process.read::<i32>(scanned).ok()?
+        };
+
+        let object_memory = {
+            let scanned = match build {
+                EngineBuild::Sonic1And2 => {
+                    const SIG: Signature<11> = Signature::new("EB ?? 8B CF E8 ?? ?? ?? ?? 8B 3D");
+                    SIG.scan_process_range(process, main_module_range)
+                }
+                EngineBuild::SonicCD => {
+                    const SIG: Signature<8> = Signature::new("EB ?? 45 33 FF 45 85 D2");
+                    SIG.scan_process_range(process, main_module_range)
+                }
+            }
+            .or_else(|| crate::address_error(owner, "object_memory", "signature scan"))?
+                + 1;
+            let temp_addr = scanned + 0x4 + process.read::<u8>(scanned).ok()?;
+            temp_addr + 0x4 + process.read::<i32>(temp_addr).ok()?
+        };
+
+        let mut engine = Self {
+            process,
+            main_module_range,
+            owner,
+            build,
+            jump_table,
+            object_memory,
+            global_variables: Address::NULL,
+            // Only needed to look up optional variables by name, so a failure is not fatal
+            global_variable_names: Self::find_global_variable_names(
+                process,
+                main_module_range,
+                owner,
+                build,
+            ),
+        };
+        engine.global_variables =
+            engine.jump_table_case("global_variables", build.global_case(), 15, 0, true)?;
+        Some(engine)
+    }
+
+    /// Address of an engine variable such as `stage.listPos`.
+    pub fn stage_variable(&self, variable: StageVariable) -> Option<Address> {
+        self.jump_table_case(
+            variable.name(),
+            self.build.stage_variable_case(variable),
+            self.build.case_offset(),
+            0,
+            false,
+        )
+    }
+
+    /// Address of a global variable declared by the game, looked up by name in the
+    /// engine's name table, such as `player.rings`.
+    pub fn global(&self, name: &str) -> Option<Address> {
+        match self.global_index(name) {
+            Ok(index) => self.validate(name, self.global_variables + index * 0x4),
+            Err(step) => crate::address_error(self.owner, name, step),
+        }
+    }
+
+    /// Same as `global`, falling back to the index the variable has always been read at
+    /// when the name table or the name itself is missing.
+    pub fn global_or(&self, name: &str, fallback_index: u64) -> Option<Address> {
+        let index = self.global_index(name).unwrap_or(fallback_index);
+        self.validate(name, self.global_variables + index * 0x4)
+    }
+
+    /// Address at the given offset into the stage's object memory.
+    pub fn object_memory(&self, name: &str, offset: u64) -> Option<Address> {
+        self.validate(name, self.object_memory + offset)
+    }
+
+    fn global_index(&self, name: &str) -> Result<u64, &'static str> {
+        let Some(names) = self.global_variable_names else {
+            return Err("global variable name table");
+        };
+
+        (0..GLOBAL_VARIABLE_COUNT)
+            .find(|index| {
+                self.process
+                    .read::<ArrayCString<GLOBAL_VARIABLE_NAME_LENGTH>>(
+                        names + index * GLOBAL_VARIABLE_NAME_LENGTH as u64,
+                    )
+                    .is_ok_and(|entry| entry.matches(name))
+            })
+            .ok_or("global variable lookup")
+    }
+
+    fn find_global_variable_names(
+        process: &Process,
+        main_module_range: (Address, u64),
+        owner: &str,
+        build: EngineBuild,
+    ) -> Option<Address> {
+        const NAME: &str = "global_variable_names";
+
+        // lea rdx, [globalVariableNames] ; shl rcx, 5
+        let scanned = match build {
+            EngineBuild::Sonic1And2 => {
+                const SIG: Signature<11> = Signature::new("48 8D 15 ?? ?? ?? ?? 48 C1 E1 05");
+                crate::scan_unique(process, main_module_range, &SIG, owner, NAME)
+            }
+            EngineBuild::SonicCD => {
+                const SIG: Signature<14> =
+                    Signature::new("48 8D 0D ?? ?? ?? ?? 48 C1 E0 05 48 03 C1");
+                crate::scan_unique(process, main_module_range, &SIG, owner, NAME)
+            }
+        }? + 3;
+        Some(scanned + 0x4 + process.read::<i32>(scanned).ok()?)
+    }
+
+    fn jump_table_case(
+        &self,
+        name: &str,
+        case: u64,
+        case_offset: u64,
+        offset: u64,
+        absolute: bool,
+    ) -> Option<Address> {
+        let Ok(temp_offset) = self.process.read::<i32>(self.jump_table + case * 0x4) else {
+            return crate::address_error(self.owner, name, "jump table entry");
+        };
+        let temp_offset_2 = self.main_module_range.0 + temp_offset + case_offset;
+        let Ok(displacement) = self.process.read::<i32>(temp_offset_2) else {
+            return crate::address_error(self.owner, name, "variable displacement");
+        };
+
+        let address = if absolute {
+            self.main_module_range.0 + displacement + offset
+        } else {
+            temp_offset_2 + 0x4 + displacement + offset
+        };
+        self.validate(name, address)
+    }
+
+    fn validate(&self, name: &str, address: Address) -> Option<Address> {
+        crate::module_address(self.main_module_range, self.owner, name, address)
+    }
+}
+
+/// Engine variables that every RetroEngine v4 game reads the same way.
+pub struct RetroEngine {
+    // Extras: a missing one only hides its variable or setting
+    stage_minutes: Option<Address>,
+    stage_seconds: Option<Address>,
+    stage_centiseconds: Option<Address>,
+    stage_state: Option<Address>,
+    rings: Option<Address>,
+    score: Option<Address>,
+    lives: Option<Address>,
+}
+
+impl RetroEngine {
+    pub fn new(resolver: &Resolver<'_>) -> Self {
+        Self {
+            stage_minutes: resolver.stage_variable(StageVariable::Minutes),
+            stage_seconds: resolver.stage_variable(StageVariable::Seconds),
+            stage_centiseconds: resolver.stage_variable(StageVariable::MilliSeconds),
+            stage_state: resolver.stage_variable(StageVariable::State),
+            rings: resolver.global("player.rings"),
+            score: resolver.global("player.score"),
+            lives: resolver.global("player.lives"),
+        }
+    }
+
+    pub fn print_addresses(&self, owner: &str) {
+        crate::print_addresses(
+            owner,
+            &[
+                ("stage_minutes", self.stage_minutes),
+                ("stage_seconds", self.stage_seconds),
+                ("stage_centiseconds", self.stage_centiseconds),
+                ("stage_state", self.stage_state),
+                ("rings", self.rings),
+                ("score", self.score),
+                ("lives", self.lives),
+            ],
+        );
+    }
+
+    /// Whether the engine is loading a stage (`STAGEMODE_LOAD`).
+    pub fn is_stage_loading(&self, process: &Process) -> bool {
+        self.stage_state
+            .is_some_and(|addr| process.read::<u8>(addr).is_ok_and(|val| val == 0))
+    }
+
+    pub fn get_stage_time(&self, process: &Process) -> Option<StageTime> {
+        Some(StageTime::from_centiseconds(
+            process.read(self.stage_minutes?).ok()?,
+            process.read(self.stage_seconds?).ok()?,
+            process.read(self.stage_centiseconds?).ok()?,
+        ))
+    }
+
+    pub fn get_rings(&self, process: &Process) -> Option<u16> {
+        process.read(self.rings?).ok()
+    }
+
+    pub fn get_score(&self, process: &Process) -> Option<u32> {
+        process.read(self.score?).ok()
+    }
+
+    pub fn get_lives(&self, process: &Process) -> Option<u8> {
+        process.read(self.lives?).ok()
+    }
+}
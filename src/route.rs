@@ -0,0 +1,887 @@
+use crate::{sonic3::Character, LevelID, Settings};
+
+/// A single edge of the split route. Leaving `from` for any of the levels in `to`
+/// triggers a split, provided the setting for the current game mode is enabled.
+pub struct RouteEdge {
+    pub from: LevelID,
+    pub to: &'static [LevelID],
+    pub story: fn(&Settings) -> bool,
+    pub single: fn(&Settings) -> bool,
+}
+
+impl RouteEdge {
+    pub fn find(level: LevelID) -> Option<&'static Self> {
+        ROUTE.iter().find(|edge| edge.from == level)
+    }
+
+    /// Whether going to `to` ends a segment of the given granularity.
+    pub fn splits_at(&self, to: LevelID, granularity: SplitGranularity) -> bool {
+        match granularity {
+            SplitGranularity::Act => true,
+            SplitGranularity::Zone => to.is_ending() || self.from.zone() != to.zone(),
+            SplitGranularity::Game => to.is_ending(),
+        }
+    }
+
+    pub fn is_enabled(&self, settings: &Settings, story_mode: bool) -> bool {
+        if story_mode {
+            (self.story)(settings)
+        } else {
+            (self.single)(settings)
+        }
+    }
+}
+
+/// How often the route splits: on every act, when a zone is finished or only when
+/// the game is beaten.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SplitGranularity {
+    Act,
+    Zone,
+    Game,
+}
+
+/// A Sonic 3 & Knuckles edge that only applies to some characters. It takes precedence
+/// over the main route table, and `skipped` lists the acts the character never visits.
+pub struct CharacterEdge {
+    pub characters: &'static [Character],
+    pub edge: RouteEdge,
+    pub skipped: &'static [LevelID],
+}
+
+impl CharacterEdge {
+    pub fn find(level: LevelID, character: Character) -> Option<&'static Self> {
+        CHARACTER_ROUTE
+            .iter()
+            .find(|val| val.edge.from == level && val.characters.contains(&character))
+    }
+
+    /// Splits to skip around the one this edge fired on its way to `reached`. The main
+    /// route from `from` through each skipped act up to `reached` would have split on
+    /// every one of its edges that ends a segment, and the split that just fired stands
+    /// in for one of them.
+    pub fn skipped_splits(
+        &self,
+        reached: LevelID,
+        granularity: SplitGranularity,
+        is_enabled: impl Fn(&RouteEdge) -> bool,
+    ) -> SkippedSplits {
+        let levels = || core::iter::once(self.edge.from).chain(self.skipped.iter().copied());
+
+        let count = levels()
+            .zip(levels().skip(1).chain([reached]))
+            .filter_map(|(level, next)| Some((RouteEdge::find(level)?, next)))
+            .filter(|&(edge, next)| edge.splits_at(next, granularity) && is_enabled(edge))
+            .count()
+            .saturating_sub(1);
+
+        // Reaching the ending finishes the run, which only happens if its split
+        // lands on the last segment
+        if reached.is_ending() {
+            SkippedSplits {
+                before: count,
+                after: 0,
+            }
+        } else {
+            SkippedSplits {
+                before: 0,
+                after: count,
+            }
+        }
+    }
+}
+
+/// Splits to skip on either side of the split fired by a `CharacterEdge`.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct SkippedSplits {
+    pub before: usize,
+    pub after: usize,
+}
+
+pub const CHARACTER_ROUTE: &[CharacterEdge] = &[
+    // Knuckles flies straight past Flying Battery
+    CharacterEdge {
+        characters: &[Character::Knuckles],
+        edge: RouteEdge {
+            from: LevelID::Sonic3_MushroomHillAct2,
+            to: &[LevelID::Sonic3_SandopolisAct1, LevelID::Sonic3_HiddenPalace],
+            story: |s| s.story_s3_mushroom_hill_2,
+            single: |s| s.s3_mushroom_hill_2,
+        },
+        skipped: &[
+            LevelID::Sonic3_FlyingBatteryAct1,
+            LevelID::Sonic3_FlyingBatteryAct2,
+        ],
+    },
+    // Knuckles' story ends after the fight with Mecha Sonic in Sky Sanctuary
+    CharacterEdge {
+        characters: &[Character::Knuckles],
+        edge: RouteEdge {
+            from: LevelID::Sonic3_SkySanctuary,
+            to: &[LevelID::Sonic3_Ending],
+            story: |s| s.story_s3_sky_sanctuary,
+            single: |s| s.s3_sky_sanctuary,
+        },
+        skipped: &[
+            LevelID::Sonic3_DeathEggAct1,
+            LevelID::Sonic3_DeathEggAct2,
+            LevelID::Sonic3_Doomsday,
+        ],
+    },
+    // Tails' story ends after Death Egg Zone Act 2, so Doomsday is never reached
+    CharacterEdge {
+        characters: &[Character::Tails],
+        edge: RouteEdge {
+            from: LevelID::Sonic3_DeathEggAct2,
+            to: &[LevelID::Sonic3_Ending],
+            story: |s| s.story_s3_death_egg_2,
+            single: |s| s.s3_death_egg_2,
+        },
+        skipped: &[LevelID::Sonic3_Doomsday],
+    },
+];
+
+pub const ROUTE: &[RouteEdge] = &[
+    RouteEdge {
+        from: LevelID::Sonic1_GreenHillAct1,
+        to: &[LevelID::Sonic1_GreenHillAct2],
+        story: |s| s.story_s1_green_hill_1,
+        single: |s| s.s1_green_hill_1,
+    },
+    RouteEdge {
+        from: LevelID::Sonic1_GreenHillAct2,
+        to: &[LevelID::Sonic1_GreenHillAct3],
+        story: |s| s.story_s1_green_hill_2,
+        single: |s| s.s1_green_hill_2,
+    },
+    RouteEdge {
+        from: LevelID::Sonic1_GreenHillAct3,
+        to: &[LevelID::Sonic1_MarbleAct1],
+        story: |s| s.story_s1_green_hill_3,
+        single: |s| s.s1_green_hill_3,
+    },
+    RouteEdge {
+        from: LevelID::Sonic1_MarbleAct1,
+        to: &[LevelID::Sonic1_MarbleAct2],
+        story: |s| s.story_s1_marble_1,
+        single: |s| s.s1_marble_1,
+    },
+    RouteEdge {
+        from: LevelID::Sonic1_MarbleAct2,
+        to: &[LevelID::Sonic1_MarbleAct3],
+        story: |s| s.story_s1_marble_2,
+        single: |s| s.s1_marble_2,
+    },
+    RouteEdge {
+        from: LevelID::Sonic1_MarbleAct3,
+        to: &[LevelID::Sonic1_SpringYardAct1],
+        story: |s| s.story_s1_marble_3,
+        single: |s| s.s1_marble_3,
+    },
+    RouteEdge {
+        from: LevelID::Sonic1_SpringYardAct1,
+        to: &[LevelID::Sonic1_SpringYardAct2],
+        story: |s| s.story_s1_spring_yard_1,
+        single: |s| s.s1_spring_yard_1,
+    },
+    RouteEdge {
+        from: LevelID::Sonic1_SpringYardAct2,
+        to: &[LevelID::Sonic1_SpringYardAct3],
+        story: |s| s.story_s1_spring_yard_2,
+        single: |s| s.s1_spring_yard_2,
+    },
+    RouteEdge {
+        from: LevelID::Sonic1_SpringYardAct3,
+        to: &[LevelID::Sonic1_LabyrinthAct1],
+        story: |s| s.story_s1_spring_yard_3,
+        single: |s| s.s1_spring_yard_3,
+    },
+    RouteEdge {
+        from: LevelID::Sonic1_LabyrinthAct1,
+        to: &[LevelID::Sonic1_LabyrinthAct2],
+        story: |s| s.story_s1_labyrinth_1,
+        single: |s| s.s1_labyrinth_1,
+    },
+    RouteEdge {
+        from: LevelID::Sonic1_LabyrinthAct2,
+        to: &[LevelID::Sonic1_LabyrinthAct3],
+        story: |s| s.story_s1_labyrinth_2,
+        single: |s| s.s1_labyrinth_2,
+    },
+    RouteEdge {
+        from: LevelID::Sonic1_LabyrinthAct3,
+        to: &[LevelID::Sonic1_StarLightAct1],
+        story: |s| s.story_s1_labyrinth_3,
+        single: |s| s.s1_labyrinth_3,
+    },
+    RouteEdge {
+        from: LevelID::Sonic1_StarLightAct1,
+        to: &[LevelID::Sonic1_StarLightAct2],
+        story: |s| s.story_s1_star_light_1,
+        single: |s| s.s1_star_light_1,
+    },
+    RouteEdge {
+        from: LevelID::Sonic1_StarLightAct2,
+        to: &[LevelID::Sonic1_StarLightAct3],
+        story: |s| s.story_s1_star_light_2,
+        single: |s| s.s1_star_light_2,
+    },
+    RouteEdge {
+        from: LevelID::Sonic1_StarLightAct3,
+        to: &[LevelID::Sonic1_ScrapBrainAct1],
+        story: |s| s.story_s1_star_light_3,
+        single: |s| s.s1_star_light_3,
+    },
+    RouteEdge {
+        from: LevelID::Sonic1_ScrapBrainAct1,
+        to: &[LevelID::Sonic1_ScrapBrainAct2],
+        story: |s| s.story_s1_scrap_brain_1,
+        single: |s| s.s1_scrap_brain_1,
+    },
+    RouteEdge {
+        from: LevelID::Sonic1_ScrapBrainAct2,
+        to: &[LevelID::Sonic1_ScrapBrainAct3],
+        story: |s| s.story_s1_scrap_brain_2,
+        single: |s| s.s1_scrap_brain_2,
+    },
+    RouteEdge {
+        from: LevelID::Sonic1_ScrapBrainAct3,
+        to: &[LevelID::Sonic1_FinalZone],
+        story: |s| s.story_s1_scrap_brain_3,
+        single: |s| s.s1_scrap_brain_3,
+    },
+    RouteEdge {
+        from: LevelID::Sonic1_FinalZone,
+        to: &[LevelID::Sonic1_Ending],
+        story: |s| s.story_s1_final_zone,
+        single: |s| s.s1_final_zone,
+    },
+    RouteEdge {
+        from: LevelID::Sonic2_EmeraldHillAct1,
+        to: &[LevelID::Sonic2_EmeraldHillAct2],
+        story: |s| s.story_s2_emerald_hill_1,
+        single: |s| s.s2_emerald_hill_1,
+    },
+    RouteEdge {
+        from: LevelID::Sonic2_EmeraldHillAct2,
+        to: &[LevelID::Sonic2_ChemicalPlantAct1],
+        story: |s| s.story_s2_emerald_hill_2,
+        single: |s| s.s2_emerald_hill_2,
+    },
+    RouteEdge {
+        from: LevelID::Sonic2_ChemicalPlantAct1,
+        to: &[LevelID::Sonic2_ChemicalPlantAct2],
+        story: |s| s.story_s2_chemical_plant_1,
+        single: |s| s.s2_chemical_plant_1,
+    },
+    RouteEdge {
+        from: LevelID::Sonic2_ChemicalPlantAct2,
+        to: &[LevelID::Sonic2_AquaticRuinAct1],
+        story: |s| s.story_s2_chemical_plant_2,
+        single: |s| s.s2_chemical_plant_2,
+    },
+    RouteEdge {
+        from: LevelID::Sonic2_AquaticRuinAct1,
+        to: &[LevelID::Sonic2_AquaticRuinAct2],
+        story: |s| s.story_s2_aquatic_ruin_1,
+        single: |s| s.s2_aquatic_ruin_1,
+    },
+    RouteEdge {
+        from: LevelID::Sonic2_AquaticRuinAct2,
+        to: &[LevelID::Sonic2_CasinoNightAct1],
+        story: |s| s.story_s2_aquatic_ruin_2,
+        single: |s| s.s2_aquatic_ruin_2,
+    },
+    RouteEdge {
+        from: LevelID::Sonic2_CasinoNightAct1,
+        to: &[LevelID::Sonic2_CasinoNightAct2],
+        story: |s| s.story_s2_casino_night_1,
+        single: |s| s.s2_casino_night_1,
+    },
+    RouteEdge {
+        from: LevelID::Sonic2_CasinoNightAct2,
+        to: &[LevelID::Sonic2_HillTopAct1],
+        story: |s| s.story_s2_casino_night_2,
+        single: |s| s.s2_casino_night_2,
+    },
+    RouteEdge {
+        from: LevelID::Sonic2_HillTopAct1,
+        to: &[LevelID::Sonic2_HillTopAct2],
+        story: |s| s.story_s2_hill_top_1,
+        single: |s| s.s2_hill_top_1,
+    },
+    RouteEdge {
+        from: LevelID::Sonic2_HillTopAct2,
+        to: &[LevelID::Sonic2_MysticCaveAct1],
+        story: |s| s.story_s2_hill_top_2,
+        single: |s| s.s2_hill_top_2,
+    },
+    RouteEdge {
+        from: LevelID::Sonic2_MysticCaveAct1,
+        to: &[LevelID::Sonic2_MysticCaveAct2],
+        story: |s| s.story_s2_mystic_cave_1,
+        single: |s| s.s2_mystic_cave_1,
+    },
+    RouteEdge {
+        from: LevelID::Sonic2_MysticCaveAct2,
+        to: &[LevelID::Sonic2_OilOceanAct1],
+        story: |s| s.story_s2_mystic_cave_2,
+        single: |s| s.s2_mystic_cave_2,
+    },
+    RouteEdge {
+        from: LevelID::Sonic2_OilOceanAct1,
+        to: &[LevelID::Sonic2_OilOceanAct2],
+        story: |s| s.story_s2_oil_ocean_1,
+        single: |s| s.s2_oil_ocean_1,
+    },
+    RouteEdge {
+        from: LevelID::Sonic2_OilOceanAct2,
+        to: &[LevelID::Sonic2_MetropolisAct1],
+        story: |s| s.story_s2_oil_ocean_2,
+        single: |s| s.s2_oil_ocean_2,
+    },
+    RouteEdge {
+        from: LevelID::Sonic2_MetropolisAct1,
+        to: &[LevelID::Sonic2_MetropolisAct2],
+        story: |s| s.story_s2_metropolis_1,
+        single: |s| s.s2_metropolis_1,
+    },
+    RouteEdge {
+        from: LevelID::Sonic2_MetropolisAct2,
+        to: &[LevelID::Sonic2_MetropolisAct3],
+        story: |s| s.story_s2_metropolis_2,
+        single: |s| s.s2_metropolis_2,
+    },
+    RouteEdge {
+        from: LevelID::Sonic2_MetropolisAct3,
+        to: &[LevelID::Sonic2_SkyChase],
+        story: |s| s.story_s2_metropolis_3,
+        single: |s| s.s2_metropolis_3,
+    },
+    RouteEdge {
+        from: LevelID::Sonic2_SkyChase,
+        to: &[LevelID::Sonic2_WingFortress],
+        story: |s| s.story_s2_sky_chase,
+        single: |s| s.s2_sky_chase,
+    },
+    RouteEdge {
+        from: LevelID::Sonic2_WingFortress,
+        to: &[LevelID::Sonic2_DeathEgg],
+        story: |s| s.story_s2_wing_fortress,
+        single: |s| s.s2_wing_fortress,
+    },
+    RouteEdge {
+        from: LevelID::Sonic2_DeathEgg,
+        to: &[LevelID::Sonic2_Ending],
+        story: |s| s.story_s2_death_egg,
+        single: |s| s.s2_death_egg,
+    },
+    RouteEdge {
+        from: LevelID::Sonic3_AngelIslandAct1,
+        to: &[LevelID::Sonic3_AngelIslandAct2],
+        story: |s| s.story_s3_angel_island_1,
+        single: |s| s.s3_angel_island_1,
+    },
+    RouteEdge {
+        from: LevelID::Sonic3_AngelIslandAct2,
+        to: &[LevelID::Sonic3_HydrocityAct1],
+        story: |s| s.story_s3_angel_island_2,
+        single: |s| s.s3_angel_island_2,
+    },
+    RouteEdge {
+        from: LevelID::Sonic3_HydrocityAct1,
+        to: &[LevelID::Sonic3_HydrocityAct2],
+        story: |s| s.story_s3_hydrocity_1,
+        single: |s| s.s3_hydrocity_1,
+    },
+    RouteEdge {
+        from: LevelID::Sonic3_HydrocityAct2,
+        to: &[LevelID::Sonic3_MarbleGardenAct1],
+        story: |s| s.story_s3_hydricity_2,
+        single: |s| s.s3_hydrocity_2,
+    },
+    RouteEdge {
+        from: LevelID::Sonic3_MarbleGardenAct1,
+        to: &[LevelID::Sonic3_MarbleGardenAct2],
+        story: |s| s.story_s3_marble_garden_1,
+        single: |s| s.s3_marble_garden_1,
+    },
+    RouteEdge {
+        from: LevelID::Sonic3_MarbleGardenAct2,
+        to: &[LevelID::Sonic3_CarnivalNightAct1],
+        story: |s| s.story_s3_marble_garden_2,
+        single: |s| s.s3_marble_garden_2,
+    },
+    RouteEdge {
+        from: LevelID::Sonic3_CarnivalNightAct1,
+        to: &[LevelID::Sonic3_CarnivalNightAct2],
+        story: |s| s.story_s3_carnival_night_1,
+        single: |s| s.s3_carnival_night_1,
+    },
+    RouteEdge {
+        from: LevelID::Sonic3_CarnivalNightAct2,
+        to: &[LevelID::Sonic3_IceCapAct1],
+        story: |s| s.story_s3_carnival_night_2,
+        single: |s| s.s3_carnival_night_2,
+    },
+    RouteEdge {
+        from: LevelID::Sonic3_IceCapAct1,
+        to: &[LevelID::Sonic3_IceCapAct2],
+        story: |s| s.story_s3_ice_cap_1,
+        single: |s| s.s3_ice_cap_1,
+    },
+    RouteEdge {
+        from: LevelID::Sonic3_IceCapAct2,
+        to: &[LevelID::Sonic3_LaunchBaseAct1],
+        story: |s| s.story_s3_ice_cap_2,
+        single: |s| s.s3_ice_cap_2,
+    },
+    RouteEdge {
+        from: LevelID::Sonic3_LaunchBaseAct1,
+        to: &[LevelID::Sonic3_LaunchBaseAct2],
+        story: |s| s.story_s3_launch_base_1,
+        single: |s| s.s3_launch_base_1,
+    },
+    RouteEdge {
+        from: LevelID::Sonic3_LaunchBaseAct2,
+        to: &[LevelID::Sonic3_MushroomHillAct1],
+        story: |s| s.story_s3_launch_base_2,
+        single: |s| s.s3_launch_base_2,
+    },
+    RouteEdge {
+        from: LevelID::Sonic3_MushroomHillAct1,
+        to: &[
+            LevelID::Sonic3_MushroomHillAct2,
+            LevelID::Sonic3_HiddenPalace,
+        ],
+        story: |s| s.story_s3_mushroom_hill_1,
+        single: |s| s.s3_mushroom_hill_1,
+    },
+    RouteEdge {
+        from: LevelID::Sonic3_MushroomHillAct2,
+        to: &[
+            LevelID::Sonic3_FlyingBatteryAct1,
+            LevelID::Sonic3_HiddenPalace,
+        ],
+        story: |s| s.story_s3_mushroom_hill_2,
+        single: |s| s.s3_mushroom_hill_2,
+    },
+    RouteEdge {
+        from: LevelID::Sonic3_FlyingBatteryAct1,
+        to: &[
+            LevelID::Sonic3_FlyingBatteryAct2,
+            LevelID::Sonic3_HiddenPalace,
+        ],
+        story: |s| s.story_s3_flying_battery_1,
+        single: |s| s.s3_flying_battery_1,
+    },
+    RouteEdge {
+        from: LevelID::Sonic3_FlyingBatteryAct2,
+        to: &[LevelID::Sonic3_SandopolisAct1, LevelID::Sonic3_HiddenPalace],
+        story: |s| s.story_s3_flying_battery_2,
+        single: |s| s.s3_flying_battery_2,
+    },
+    RouteEdge {
+        from: LevelID::Sonic3_SandopolisAct1,
+        to: &[LevelID::Sonic3_SandopolisAct2, LevelID::Sonic3_HiddenPalace],
+        story: |s| s.story_s3_sandopolis_1,
+        single: |s| s.s3_sandopolis_1,
+    },
+    RouteEdge {
+        from: LevelID::Sonic3_SandopolisAct2,
+        to: &[LevelID::Sonic3_LavaReefAct1, LevelID::Sonic3_HiddenPalace],
+        story: |s| s.story_s3_sandopolis_2,
+        single: |s| s.s3_sandopolis_2,
+    },
+    RouteEdge {
+        from: LevelID::Sonic3_LavaReefAct1,
+        to: &[LevelID::Sonic3_LavaReefAct2, LevelID::Sonic3_HiddenPalace],
+        story: |s| s.story_s3_lava_reef_1,
+        single: |s| s.s3_lava_reef_1,
+    },
+    RouteEdge {
+        from: LevelID::Sonic3_LavaReefAct2,
+        to: &[LevelID::Sonic3_HiddenPalace],
+        story: |s| s.story_s3_lava_reef_2,
+        single: |s| s.s3_lava_reef_2,
+    },
+    RouteEdge {
+        from: LevelID::Sonic3_HiddenPalace,
+        to: &[LevelID::Sonic3_SkySanctuary],
+        story: |s| s.story_s3_hidden_palace,
+        single: |s| s.s3_hidden_palace,
+    },
+    RouteEdge {
+        from: LevelID::Sonic3_SkySanctuary,
+        to: &[LevelID::Sonic3_DeathEggAct1, LevelID::Sonic3_Ending],
+        story: |s| s.story_s3_sky_sanctuary,
+        single: |s| s.s3_sky_sanctuary,
+    },
+    RouteEdge {
+        from: LevelID::Sonic3_DeathEggAct1,
+        to: &[LevelID::Sonic3_DeathEggAct2],
+        story: |s| s.story_s3_death_egg_1,
+        single: |s| s.s3_death_egg_1,
+    },
+    RouteEdge {
+        from: LevelID::Sonic3_DeathEggAct2,
+        to: &[LevelID::Sonic3_Doomsday, LevelID::Sonic3_Ending],
+        story: |s| s.story_s3_death_egg_2,
+        single: |s| s.s3_death_egg_2,
+    },
+    RouteEdge {
+        from: LevelID::Sonic3_Doomsday,
+        to: &[LevelID::Sonic3_Ending],
+        story: |s| s.story_s3_doomsday,
+        single: |s| s.s3_doomsday,
+    },
+    RouteEdge {
+        from: LevelID::SonicCD_PalmtreePanicAct1,
+        to: &[LevelID::SonicCD_PalmtreePanicAct2],
+        story: |s| s.story_scd_palmtree_panic_1,
+        single: |s| s.scd_palmtree_panic_1,
+    },
+    RouteEdge {
+        from: LevelID::SonicCD_PalmtreePanicAct2,
+        to: &[LevelID::SonicCD_PalmtreePanicAct3],
+        story: |s| s.story_scd_palmtree_panic_2,
+        single: |s| s.scd_palmtree_panic_2,
+    },
+    RouteEdge {
+        from: LevelID::SonicCD_PalmtreePanicAct3,
+        to: &[LevelID::SonicCD_CollisionChaosAct1],
+        story: |s| s.story_scd_palmtree_panic_3,
+        single: |s| s.scd_palmtree_panic_3,
+    },
+    RouteEdge {
+        from: LevelID::SonicCD_CollisionChaosAct1,
+        to: &[LevelID::SonicCD_CollisionChaosAct2],
+        story: |s| s.story_scd_collision_chaos_1,
+        single: |s| s.scd_collision_chaos_1,
+    },
+    RouteEdge {
+        from: LevelID::SonicCD_CollisionChaosAct2,
+        to: &[LevelID::SonicCD_CollisionChaosAct3],
+        story: |s| s.story_scd_collision_chaos_2,
+        single: |s| s.scd_collision_chaos_2,
+    },
+    RouteEdge {
+        from: LevelID::SonicCD_CollisionChaosAct3,
+        to: &[LevelID::SonicCD_TidalTempestAct1],
+        story: |s| s.story_scd_collision_chaos_3,
+        single: |s| s.scd_collision_chaos_3,
+    },
+    RouteEdge {
+        from: LevelID::SonicCD_TidalTempestAct1,
+        to: &[LevelID::SonicCD_TidalTempestAct2],
+        story: |s| s.story_scd_tidal_tempest_1,
+        single: |s| s.scd_tidal_tempest_1,
+    },
+    RouteEdge {
+        from: LevelID::SonicCD_TidalTempestAct2,
+        to: &[LevelID::SonicCD_TidalTempestAct3],
+        story: |s| s.story_scd_tidal_tempest_2,
+        single: |s| s.scd_tidal_tempest_2,
+    },
+    RouteEdge {
+        from: LevelID::SonicCD_TidalTempestAct3,
+        to: &[LevelID::SonicCD_QuartzQuadrantAct1],
+        story: |s| s.story_scd_tidal_tempest_3,
+        single: |s| s.scd_tidal_tempest_3,
+    },
+    RouteEdge {
+        from: LevelID::SonicCD_QuartzQuadrantAct1,
+        to: &[LevelID::SonicCD_QuartzQuadrantAct2],
+        story: |s| s.story_scd_quartz_quadrant_1,
+        single: |s| s.scd_quartz_quadrant_1,
+    },
+    RouteEdge {
+        from: LevelID::SonicCD_QuartzQuadrantAct2,
+        to: &[LevelID::SonicCD_QuartzQuadrantAct3],
+        story: |s| s.story_scd_quartz_quadrant_2,
+        single: |s| s.scd_quartz_quadrant_2,
+    },
+    RouteEdge {
+        from: LevelID::SonicCD_QuartzQuadrantAct3,
+        to: &[LevelID::SonicCD_WackyWorkbenchAct1],
+        story: |s| s.story_scd_quartz_quadrant_3,
+        single: |s| s.scd_quartz_quadrant_3,
+    },
+    RouteEdge {
+        from: LevelID::SonicCD_WackyWorkbenchAct1,
+        to: &[LevelID::SonicCD_WackyWorkbenchAct2],
+        story: |s| s.story_scd_wacky_workbench_1,
+        single: |s| s.scd_wacky_workbench_1,
+    },
+    RouteEdge {
+        from: LevelID::SonicCD_WackyWorkbenchAct2,
+        to: &[LevelID::SonicCD_WackyWorkbenchAct3],
+        story: |s| s.story_scd_wacky_workbench_2,
+        single: |s| s.scd_wacky_workbench_2,
+    },
+    RouteEdge {
+        from: LevelID::SonicCD_WackyWorkbenchAct3,
+        to: &[LevelID::SonicCD_StardustSpeedwayAct1],
+        story: |s| s.story_scd_wacky_workbench_3,
+        single: |s| s.scd_wacky_workbench_3,
+    },
+    RouteEdge {
+        from: LevelID::SonicCD_StardustSpeedwayAct1,
+        to: &[LevelID::SonicCD_StardustSpeedwayAct2],
+        story: |s| s.story_scd_stardust_speedway_1,
+        single: |s| s.scd_stardust_speedway_1,
+    },
+    RouteEdge {
+        from: LevelID::SonicCD_StardustSpeedwayAct2,
+        to: &[LevelID::SonicCD_StardustSpeedwayAct3],
+        story: |s| s.story_scd_stardust_speedway_2,
+        single: |s| s.scd_stardust_speedway_2,
+    },
+    RouteEdge {
+        from: LevelID::SonicCD_StardustSpeedwayAct3,
+        to: &[LevelID::SonicCD_MetallicMadnessAct1],
+        story: |s| s.story_scd_stardust_speedway_3,
+        single: |s| s.scd_stardust_speedway_3,
+    },
+    RouteEdge {
+        from: LevelID::SonicCD_MetallicMadnessAct1,
+        to: &[LevelID::SonicCD_MetallicMadnessAct2],
+        story: |s| s.story_scd_metallic_madness_1,
+        single: |s| s.scd_metallic_madness_1,
+    },
+    RouteEdge {
+        from: LevelID::SonicCD_MetallicMadnessAct2,
+        to: &[LevelID::SonicCD_MetallicMadnessAct3],
+        story: |s| s.story_scd_metallic_madness_2,
+        single: |s| s.scd_metallic_madness_2,
+    },
+    RouteEdge {
+        from: LevelID::SonicCD_MetallicMadnessAct3,
+        to: &[LevelID::SonicCD_Ending],
+        story: |s| s.story_scd_metallic_madness_3,
+        single: |s| s.scd_metallic_madness_3,
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The act a route edge normally leads to: the next act declared for the same game,
+    /// or its ending after the last one.
+    fn next_act(level: LevelID) -> LevelID {
+        let same_game = |other: &&LevelID| other.game() == level.game();
+        LevelID::ALL[level as usize + 1..]
+            .iter()
+            .filter(same_game)
+            .find(|other| other.is_act())
+            .or_else(|| {
+                LevelID::ALL
+                    .iter()
+                    .filter(same_game)
+                    .find(|other| other.is_ending())
+            })
+            .copied()
+            .unwrap()
+    }
+
+    #[test]
+    fn all_levels_lists_every_variant_in_order() {
+        assert_eq!(LevelID::ALL.len(), core::mem::variant_count::<LevelID>());
+        for (i, &level) in LevelID::ALL.iter().enumerate() {
+            assert_eq!(level as usize, i, "{level:?}");
+        }
+    }
+
+    #[test]
+    fn every_act_has_exactly_one_edge() {
+        for &level in LevelID::ALL {
+            let edges = ROUTE.iter().filter(|edge| edge.from == level).count();
+            assert_eq!(edges, level.is_act() as usize, "{level:?}");
+        }
+    }
+
+    #[test]
+    fn edges_lead_to_the_next_act() {
+        for edge in ROUTE {
+            assert_eq!(edge.to[0], next_act(edge.from), "{:?}", edge.from);
+            for &to in edge.to {
+                assert_ne!(to, edge.from);
+                assert!(to.is_act() || to.is_ending(), "{:?}", edge.from);
+                assert_eq!(to.game(), edge.from.game());
+            }
+        }
+
+        assert_eq!(
+            RouteEdge::find(LevelID::Sonic1_ScrapBrainAct3).map(|edge| edge.to),
+            Some(&[LevelID::Sonic1_FinalZone][..])
+        );
+        assert_eq!(
+            RouteEdge::find(LevelID::Sonic3_DeathEggAct2).map(|edge| edge.to),
+            Some(&[LevelID::Sonic3_Doomsday, LevelID::Sonic3_Ending][..])
+        );
+        assert!(RouteEdge::find(LevelID::Unknown).is_none());
+    }
+
+    #[test]
+    fn character_edges_skip_over_a_chain_of_acts() {
+        for character_edge in CHARACTER_ROUTE {
+            let edge = &character_edge.edge;
+            assert!(edge.from.is_act());
+            assert!(!character_edge.skipped.is_empty());
+
+            // Following the main route from `from` through the skipped acts must end up
+            // where the character edge leads
+            let mut level = edge.from;
+            for &skipped in character_edge.skipped {
+                assert_eq!(RouteEdge::find(level).unwrap().to[0], skipped);
+                level = skipped;
+            }
+            assert!(RouteEdge::find(level).unwrap().to.contains(&edge.to[0]));
+            for &to in edge.to {
+                assert_ne!(to, edge.from);
+                assert!(to.is_act() || to.is_ending());
+            }
+        }
+    }
+
+    #[test]
+    fn character_edges_only_apply_to_their_characters() {
+        let find =
+            |level, character| CharacterEdge::find(level, character).map(|val| val.edge.to[0]);
+
+        for character in [Character::SonicAndTails, Character::Sonic, Character::Tails] {
+            assert_eq!(find(LevelID::Sonic3_MushroomHillAct2, character), None);
+            assert_eq!(find(LevelID::Sonic3_SkySanctuary, character), None);
+        }
+        assert_eq!(
+            find(LevelID::Sonic3_MushroomHillAct2, Character::Knuckles),
+            Some(LevelID::Sonic3_SandopolisAct1)
+        );
+        assert_eq!(
+            find(LevelID::Sonic3_SkySanctuary, Character::Knuckles),
+            Some(LevelID::Sonic3_Ending)
+        );
+
+        for character in [
+            Character::SonicAndTails,
+            Character::Sonic,
+            Character::Knuckles,
+        ] {
+            assert_eq!(find(LevelID::Sonic3_DeathEggAct2, character), None);
+        }
+        assert_eq!(
+            find(LevelID::Sonic3_DeathEggAct2, Character::Tails),
+            Some(LevelID::Sonic3_Ending)
+        );
+    }
+
+    #[test]
+    fn skipped_splits_follow_the_chain_of_skipped_acts() {
+        let skipped = |character, from, to, granularity| {
+            CharacterEdge::find(from, character)
+                .unwrap()
+                .skipped_splits(to, granularity, |_| true)
+        };
+        let before = |before| SkippedSplits { before, after: 0 };
+        let after = |after| SkippedSplits { before: 0, after };
+
+        for (character, from, to, expected) in [
+            (
+                Character::Knuckles,
+                LevelID::Sonic3_MushroomHillAct2,
+                LevelID::Sonic3_SandopolisAct1,
+                [after(2), after(1), after(0)],
+            ),
+            (
+                Character::Knuckles,
+                LevelID::Sonic3_SkySanctuary,
+                LevelID::Sonic3_Ending,
+                [before(3), before(2), before(0)],
+            ),
+            (
+                Character::Tails,
+                LevelID::Sonic3_DeathEggAct2,
+                LevelID::Sonic3_Ending,
+                [before(1), before(1), before(0)],
+            ),
+        ] {
+            for (granularity, expected) in [
+                SplitGranularity::Act,
+                SplitGranularity::Zone,
+                SplitGranularity::Game,
+            ]
+            .into_iter()
+            .zip(expected)
+            {
+                assert_eq!(
+                    skipped(character, from, to, granularity),
+                    expected,
+                    "{from:?} with {granularity:?} splits"
+                );
+            }
+        }
+
+        // Acts without a split of their own don't need skipping either
+        let edge = CharacterEdge::find(LevelID::Sonic3_MushroomHillAct2, Character::Knuckles);
+        assert_eq!(
+            edge.unwrap().skipped_splits(
+                LevelID::Sonic3_SandopolisAct1,
+                SplitGranularity::Act,
+                |edge| edge.from != LevelID::Sonic3_FlyingBatteryAct2
+            ),
+            after(1)
+        );
+    }
+
+    #[test]
+    fn splits_at_every_granularity() {
+        let splits_at =
+            |from, to, granularity| RouteEdge::find(from).unwrap().splits_at(to, granularity);
+
+        for granularity in [SplitGranularity::Act, SplitGranularity::Zone] {
+            assert!(splits_at(
+                LevelID::Sonic1_GreenHillAct3,
+                LevelID::Sonic1_MarbleAct1,
+                granularity
+            ));
+        }
+        assert!(splits_at(
+            LevelID::Sonic1_GreenHillAct1,
+            LevelID::Sonic1_GreenHillAct2,
+            SplitGranularity::Act
+        ));
+        assert!(!splits_at(
+            LevelID::Sonic1_GreenHillAct1,
+            LevelID::Sonic1_GreenHillAct2,
+            SplitGranularity::Zone
+        ));
+        assert!(!splits_at(
+            LevelID::Sonic1_GreenHillAct3,
+            LevelID::Sonic1_MarbleAct1,
+            SplitGranularity::Game
+        ));
+
+        for granularity in [
+            SplitGranularity::Act,
+            SplitGranularity::Zone,
+            SplitGranularity::Game,
+        ] {
+            assert!(splits_at(
+                LevelID::Sonic1_FinalZone,
+                LevelID::Sonic1_Ending,
+                granularity
+            ));
+            assert!(splits_at(
+                LevelID::Sonic3_SkySanctuary,
+                LevelID::Sonic3_Ending,
+                granularity
+            ));
+        }
+        assert!(!splits_at(
+            LevelID::Sonic3_SkySanctuary,
+            LevelID::Sonic3_DeathEggAct1,
+            SplitGranularity::Game
+        ));
+    }
+}
@@ -1,40 +1,124 @@
-//extern crate alloc;
-//use core::cell::RefCell;
-//use alloc::{collections::BTreeMap, string::String, borrow::ToOwned};
+use core::fmt;
+
 use asr::{string::ArrayCString, Address, Address64, Process};
 
+/// Number of distinct classes remembered by the lookup cache.
+const CACHE_SIZE: usize = 16;
+/// Size of the chunks the executable is read in when listing its classes.
+const SCAN_CHUNK_SIZE: usize = 0x1000;
+/// Longest class name read from a type descriptor.
+const CLASS_NAME_LENGTH: usize = 128;
+/// Prefix of the name of every class type descriptor.
+const CLASS_PREFIX: &[u8] = b".?AV";
+
 pub struct Rtti {
-    base_address: Address,
-    //cache: RefCell<BTreeMap<Address, String>>
+    module_range: (Address, u64),
+    cache: [Option<(Address, ArrayCString<CLASS_NAME_LENGTH>)>; CACHE_SIZE],
+    next_slot: usize,
 }
 
 impl Rtti {
-    pub const fn new(base_address: Address) -> Self {
+    pub const fn new(module_range: (Address, u64)) -> Self {
         Self {
-            base_address,
-            //cache: RefCell::new(BTreeMap::new()),
+            module_range,
+            cache: [None; CACHE_SIZE],
+            next_slot: 0,
         }
     }
 
-    pub fn lookup(&self, process: &Process, address: Address) -> Option<ArrayCString<128>> {
-        //let mut cache = self.cache.borrow_mut();
+    /// Mangled class name (e.g. `GameModeRetroEngine@game@app@@`) of the object
+    /// whose vtable is at `vtable`.
+    pub fn lookup(
+        &mut self,
+        process: &Process,
+        vtable: Address,
+    ) -> Option<ArrayCString<CLASS_NAME_LENGTH>> {
+        if let Some((_, name)) = self.cache.iter().flatten().find(|(key, _)| *key == vtable) {
+            return Some(*name);
+        }
 
-        //if let Some(found_cached) = cache.get(&address) {
-        //    Some(found_cached.to_owned())
-        //} else {
         let base = process
-            .read::<Address64>(address.add_signed(-0x8))
+            .read::<Address64>(vtable.add_signed(-0x8))
             .ok()?
             .add(0xC);
 
-        let final_addr = self.base_address + process.read::<u32>(base).ok()? + 0x10 + 0x4;
-        process.read(final_addr).ok()
+        let final_addr = self.module_range.0 + process.read::<u32>(base).ok()? + 0x10 + 0x4;
+        let name = process
+            .read::<ArrayCString<CLASS_NAME_LENGTH>>(final_addr)
+            .ok()?;
+
+        // Vtables live in the executable's read-only data, so anything outside of it was
+        // read through an object that has already been freed and must not be remembered
+        let (module_base, module_size) = self.module_range;
+        if vtable >= module_base && vtable - module_base < module_size {
+            self.cache[self.next_slot] = Some((vtable, name));
+            self.next_slot = (self.next_slot + 1) % CACHE_SIZE;
+        }
+
+        Some(name)
+    }
+
+    /// Calls `found` with the name of every class the executable has a type descriptor
+    /// for. Reads the whole module, so this is only meant to run once after attaching.
+    pub fn for_each_class(&self, process: &Process, mut found: impl FnMut(Demangled<'_>)) {
+        let (base, size) = self.module_range;
+        // Each chunk is read with enough extra bytes to finish a name starting at its end
+        let mut buf = [0; SCAN_CHUNK_SIZE + CLASS_NAME_LENGTH];
+        let mut offset = 0;
+
+        while offset < size {
+            let len = (size - offset).min(buf.len() as u64) as usize;
+            if process
+                .read_into_slice(base + offset, &mut buf[..len])
+                .is_ok()
+            {
+                let chunk = &buf[..len];
+                for start in
+                    (0..SCAN_CHUNK_SIZE.min(len)).filter(|&i| chunk[i..].starts_with(CLASS_PREFIX))
+                {
+                    let name = &chunk[start + CLASS_PREFIX.len()..];
+                    let Some(end) = name.iter().position(|&b| b == 0) else {
+                        continue;
+                    };
+                    if let Ok(name) = core::str::from_utf8(&name[..end]) {
+                        found(Demangled::new(name));
+                    }
+                }
+            }
+            offset += SCAN_CHUNK_SIZE as u64;
+        }
+    }
+}
+
+/// Readable form of a mangled RTTI class name, turning
+/// `GameModeRetroEngine@game@app@@` into `app::game::GameModeRetroEngine`.
+#[derive(Copy, Clone)]
+pub struct Demangled<'a>(&'a str);
+
+impl<'a> Demangled<'a> {
+    pub fn new(mangled: &'a str) -> Self {
+        Self(mangled.trim_end_matches('@'))
+    }
+
+    fn segments(self) -> impl Iterator<Item = &'a str> {
+        self.0.rsplit('@')
+    }
+}
+
+impl fmt::Display for Demangled<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.segments().enumerate() {
+            if i != 0 {
+                f.write_str("::")?;
+            }
+            f.write_str(segment)?;
+        }
+        Ok(())
+    }
+}
 
-        //let rtti_name = process.read::<ArrayCString<128>>(final_addr).ok()?;
-        //let name = String::from_utf8_lossy(rtti_name.as_bytes());
-        //let string = name.replace("@@", "").replace('@', "::");
-        //cache.insert(address, string.clone());
-        //Some(rtti_name)
-        //}
+impl PartialEq<&str> for Demangled<'_> {
+    fn eq(&self, path: &&str) -> bool {
+        self.segments().eq(path.split("::"))
     }
 }
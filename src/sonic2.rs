@@ -1,7 +1,9 @@
+use crate::retroengine::{EngineBuild, Resolver, RetroEngine, StageVariable};
 use crate::{GameMode, LevelID};
-use asr::{signature::Signature, watcher::Watcher, Address, Process};
+use asr::{watcher::Watcher, Address, Process};
 
 pub struct Sonic2 {
+    pub engine: RetroEngine,
     level_id: Address,
     time_bonus: Address,
     start_trigger: Address,
@@ -9,8 +11,8 @@ pub struct Sonic2 {
     game_mode: Address,
     score_tally: Address,
     continue_bonus: Address,
+    emeralds: Option<Address>,
 
-    level_watcher: Watcher<LevelID>,
     start_trigger_value: Watcher<u8>,
     time_bonus_value: Watcher<u32>,
     time_bonus_start_value: u32,
@@ -18,57 +20,47 @@ pub struct Sonic2 {
 
 impl Sonic2 {
     pub fn new(process: &Process, main_module_range: (Address, u64)) -> Option<Self> {
-        let ptr = {
-            const PTR: Signature<27> = Signature::new(
-                "3D F9 00 00 00 0F 87 ?? ?? ?? ?? 41 8B 8C 84 ?? ?? ?? ?? 49 03 CC FF ?? 41 8B 84",
-            );
-            let scanned = PTR.scan_process_range(process, main_module_range)? + 15;
-            main_module_range.0 + process.read::<i32>(scanned).ok()?
-        };
-
-        let lea = {
-            const LEA: Signature<11> = Signature::new("EB ?? 8B CF E8 ?? ?? ?? ?? 8B 3D");
-            let scanned = LEA.scan_process_range(process, main_module_range)? + 1;
-            let temp_addr = scanned + 0x4 + process.read::<u8>(scanned).ok()?;
-            temp_addr + 0x4 + process.read::<i32>(temp_addr).ok()?
-        };
-
-        let pointer_path = |offset1, offset2, offset3, absolute| {
-            if offset1 == 0 {
-                lea + offset3
-            } else {
-                let temp_offset = process.read::<i32>(ptr + offset1).unwrap_or_default();
-                let temp_offset_2 = main_module_range.0 + temp_offset + offset2;
-
-                if absolute {
-                    main_module_range.0
-                        + process.read::<i32>(temp_offset_2).unwrap_or_default()
-                        + offset3
-                } else {
-                    temp_offset_2
-                        + 0x4
-                        + process.read::<i32>(temp_offset_2).unwrap_or_default()
-                        + offset3
-                }
-            }
-        };
+        let resolver = Resolver::new(
+            process,
+            main_module_range,
+            "Sonic 2",
+            EngineBuild::Sonic1And2,
+        )?;
 
         Some(Self {
-            level_id: pointer_path(0x4 * 122, 39, 0, false),
-            time_bonus: pointer_path(0, 0, 0x20D0 + 0x30, false),
-            start_trigger: pointer_path(0, 0, 0x2418 + 0xD4, false),
-            demo_mode: pointer_path(0x4 * 17, 15, 0x5 * 4, true),
-            game_mode: pointer_path(0x4 * 17, 15, 0x8F * 4, true),
-            score_tally: pointer_path(0, 0, 0x20D0 + 0xD4, false),
-            continue_bonus: pointer_path(0, 0, 0x20D0 + 0x44, false),
-            level_watcher: Watcher::new(),
+            engine: RetroEngine::new(&resolver),
+            level_id: resolver.stage_variable(StageVariable::ListPos)?,
+            time_bonus: resolver.object_memory("time_bonus", 0x20D0 + 0x30)?,
+            start_trigger: resolver.object_memory("start_trigger", 0x2418 + 0xD4)?,
+            demo_mode: resolver.global_or("options.attractMode", 0x5)?,
+            game_mode: resolver.global_or("options.gameMode", 0x8F)?,
+            score_tally: resolver.object_memory("score_tally", 0x20D0 + 0xD4)?,
+            continue_bonus: resolver.object_memory("continue_bonus", 0x20D0 + 0x44)?,
+            emeralds: resolver.global("specialStage.emeralds"),
             start_trigger_value: Watcher::new(),
             time_bonus_value: Watcher::new(),
             time_bonus_start_value: u32::default(),
         })
     }
 
-    pub fn get_current_level(&mut self, process: &Process) -> LevelID {
+    pub fn print_addresses(&self) {
+        crate::print_addresses(
+            "Sonic 2",
+            &[
+                ("level_id", Some(self.level_id)),
+                ("time_bonus", Some(self.time_bonus)),
+                ("start_trigger", Some(self.start_trigger)),
+                ("demo_mode", Some(self.demo_mode)),
+                ("game_mode", Some(self.game_mode)),
+                ("score_tally", Some(self.score_tally)),
+                ("continue_bonus", Some(self.continue_bonus)),
+                ("emeralds", self.emeralds),
+            ],
+        );
+        self.engine.print_addresses("Sonic 2");
+    }
+
+    pub fn get_current_level(&self, process: &Process) -> LevelID {
         match process.read::<u8>(self.level_id).unwrap_or_default() {
             0 => LevelID::Sonic2_TitleScreen,
             1 | 2 => LevelID::Sonic2_Ending,
@@ -92,10 +84,7 @@ impl Sonic2 {
             23 => LevelID::Sonic2_SkyChase,
             24 => LevelID::Sonic2_WingFortress,
             25 => LevelID::Sonic2_DeathEgg,
-            _ => match self.level_watcher.pair {
-                Some(x) => x.current,
-                _ => LevelID::MainMenu,
-            },
+            _ => LevelID::Unknown,
         }
     }
 
@@ -126,6 +115,13 @@ impl Sonic2 {
         start_trigger_value.changed_from_to(&8, &9)
     }
 
+    pub fn get_chaos_emeralds(&self, process: &Process) -> Option<u8> {
+        process
+            .read::<u8>(self.emeralds?)
+            .ok()
+            .map(|val| val.count_ones() as u8)
+    }
+
     pub fn is_demo_mode(&self, process: &Process) -> bool {
         process.read(self.demo_mode).unwrap_or_default()
     }
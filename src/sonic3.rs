@@ -1,6 +1,29 @@
-use crate::{GameMode, LevelID};
+use crate::{GameMode, LevelID, StageTime};
 use asr::{signature::Signature, watcher::Watcher, Address, Process};
 
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Character {
+    SonicAndTails,
+    Sonic,
+    Tails,
+    Knuckles,
+}
+
+impl Character {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Character::SonicAndTails => "Sonic & Tails",
+            Character::Sonic => "Sonic",
+            Character::Tails => "Tails",
+            Character::Knuckles => "Knuckles",
+        }
+    }
+}
+
+/// Upper bound for the field offset read next to `game_mode`. The mode is a field of a
+/// small game object, so anything larger means the signature landed on other code.
+const GAME_MODE_OFFSET_LIMIT: u32 = 0x1000;
+
 pub struct Sonic3 {
     level_id: Address,
     level_id_apparent: Address,
@@ -8,49 +31,180 @@ pub struct Sonic3 {
     game_mode: Address,
     game_mode_offset: u64,
     hpz_flag: Address,
+    timer_frames: Option<Address>,
+    player_mode: Option<Address>,
+    emerald_count: Option<Address>,
+    blue_spheres_stage: Option<Address>,
+    time_bonus: Option<Address>,
+    demo_mode: Option<Address>,
+    rings: Option<Address>,
     level_watcher: Watcher<LevelID>,
+    time_bonus_value: Watcher<u32>,
+    time_bonus_start_value: u32,
 }
 
 impl Sonic3 {
     pub fn new(process: &Process, main_module_range: (Address, u64)) -> Option<Self> {
         let status = {
             const SIG: Signature<4> = Signature::new("0A C1 88 05");
-            let ptr = SIG.scan_process_range(process, main_module_range)? + 4;
+            let ptr = SIG
+                .scan_process_range(process, main_module_range)
+                .or_else(|| {
+                    crate::address_error("Sonic 3 & Knuckles", "status", "signature scan")
+                })?
+                + 4;
             ptr + 0x4 + process.read::<i32>(ptr).ok()?
         };
 
         let level_id = {
             const SIG: Signature<9> = Signature::new("66 89 05 ?? ?? ?? ?? 3B DF");
-            let ptr = SIG.scan_process_range(process, main_module_range)? + 3;
+            let ptr = SIG
+                .scan_process_range(process, main_module_range)
+                .or_else(|| {
+                    crate::address_error("Sonic 3 & Knuckles", "level_id", "signature scan")
+                })?
+                + 3;
             ptr + 0x4 + process.read::<i32>(ptr).ok()?
         };
 
         let level_id_apparent = {
             const SIG: Signature<9> = Signature::new("89 15 ?? ?? ?? ?? 48 8B 87");
-            let ptr = SIG.scan_process_range(process, main_module_range)? + 2;
+            let ptr = SIG
+                .scan_process_range(process, main_module_range)
+                .or_else(|| {
+                    crate::address_error(
+                        "Sonic 3 & Knuckles",
+                        "level_id_apparent",
+                        "signature scan",
+                    )
+                })?
+                + 2;
             ptr + 0x4 + process.read::<i32>(ptr).ok()?
         };
 
         let hpz_flag = {
             const SIG: Signature<9> = Signature::new("4C 39 35 ?? ?? ?? ?? 74 05");
-            let ptr = SIG.scan_process_range(process, main_module_range)? + 3;
+            let ptr = SIG
+                .scan_process_range(process, main_module_range)
+                .or_else(|| {
+                    crate::address_error("Sonic 3 & Knuckles", "hpz_flag", "signature scan")
+                })?
+                + 3;
             ptr + 0x4 + process.read::<i32>(ptr).ok()?
         };
 
+        // cmp byte ptr [Timer_frame], 59 - minutes and seconds are stored right before it
+        let timer_frames = {
+            const SIG: Signature<8> = Signature::new("80 3D ?? ?? ?? ?? 3B 72");
+            extra_address(process, main_module_range, &SIG, "timer_frames", 2, 0x5)
+        };
+
+        let player_mode = {
+            const SIG: Signature<10> = Signature::new("0F B7 05 ?? ?? ?? ?? 83 F8 03");
+            extra_address(process, main_module_range, &SIG, "player_mode", 3, 0x4)
+        };
+
+        // Chaos Emerald count, immediately followed by the Super Emerald count
+        let emerald_count = {
+            const SIG: Signature<9> = Signature::new("0F B6 05 ?? ?? ?? ?? 3C 07");
+            extra_address(process, main_module_range, &SIG, "emerald_count", 3, 0x4)
+        };
+
+        // movzx eax, word ptr [Blue_spheres_stage] ; inc ax - the stage only moves on once cleared
+        let blue_spheres_stage = {
+            const SIG: Signature<10> = Signature::new("0F B7 05 ?? ?? ?? ?? 66 FF C0");
+            extra_address(
+                process,
+                main_module_range,
+                &SIG,
+                "blue_spheres_stage",
+                3,
+                0x4,
+            )
+        };
+
+        // sub word ptr [Time_bonus_countdown], 10 - the ring bonus is stored right after it
+        let time_bonus = {
+            const SIG: Signature<8> = Signature::new("66 83 2D ?? ?? ?? ?? 0A");
+            extra_address(process, main_module_range, &SIG, "time_bonus", 3, 0x5)
+        };
+
+        // tst word ptr [Demo_mode_flag] before the title screen attract timer runs out
+        let demo_mode = {
+            const SIG: Signature<9> = Signature::new("66 83 3D ?? ?? ?? ?? 00 75");
+            extra_address(process, main_module_range, &SIG, "demo_mode", 3, 0x5)
+        };
+
+        // add word ptr [Ring_count], 1
+        let rings = {
+            const SIG: Signature<9> = Signature::new("66 83 05 ?? ?? ?? ?? 01 66");
+            extra_address(process, main_module_range, &SIG, "rings", 3, 0x5)
+        };
+
         let ptr = {
             const SIG: Signature<25> = Signature::new(
                 "41 83 F8 05 0F 85 ?? ?? ?? ?? 83 F9 16 0F 87 ?? ?? ?? ?? 48 63 C1 48 8D 0D",
             );
-            let pptr = SIG.scan_process_range(process, main_module_range)? + 32;
-            main_module_range.0 + process.read::<i32>(pptr).ok()?
+            let pptr = SIG
+                .scan_process_range(process, main_module_range)
+                .or_else(|| {
+                    crate::address_error("Sonic 3 & Knuckles", "game_mode", "signature scan")
+                })?
+                + 32;
+            let Ok(jump_table) = process.read::<i32>(pptr) else {
+                return crate::address_error("Sonic 3 & Knuckles", "game_mode", "jump table");
+            };
+            crate::module_address(
+                main_module_range,
+                "Sonic 3 & Knuckles",
+                "game_mode",
+                main_module_range.0 + jump_table,
+            )?
+        };
+        let case = {
+            let Ok(temp_offset) = process.read::<i32>(ptr) else {
+                return crate::address_error("Sonic 3 & Knuckles", "game_mode", "jump table entry");
+            };
+            crate::module_address(
+                main_module_range,
+                "Sonic 3 & Knuckles",
+                "game_mode",
+                main_module_range.0 + temp_offset + 3,
+            )?
         };
-        let pointer_case = |offset1, offset2| {
-            let temp_offset = process.read::<i32>(ptr + offset1).unwrap_or_default();
-            main_module_range.0 + temp_offset + offset2
+        let game_mode = {
+            let Ok(displacement) = process.read::<i32>(case) else {
+                return crate::address_error(
+                    "Sonic 3 & Knuckles",
+                    "game_mode",
+                    "variable displacement",
+                );
+            };
+            crate::module_address(
+                main_module_range,
+                "Sonic 3 & Knuckles",
+                "game_mode",
+                case + 0x4 + displacement,
+            )?
+        };
+        // Offset of the mode within the object game_mode points to
+        let game_mode_offset = match process.read::<u32>(case + 0x6) {
+            Ok(offset) if offset < GAME_MODE_OFFSET_LIMIT => offset as u64,
+            Ok(_) => {
+                return crate::address_error(
+                    "Sonic 3 & Knuckles",
+                    "game_mode_offset",
+                    "offset out of range",
+                )
+            }
+            Err(_) => {
+                return crate::address_error(
+                    "Sonic 3 & Knuckles",
+                    "game_mode_offset",
+                    "offset read",
+                )
+            }
         };
-        let b_base = pointer_case(0, 3);
-        let game_mode = b_base + 0x4 + process.read::<i32>(b_base).ok()?;
-        let game_mode_offset = process.read::<u32>(b_base + 0x6).unwrap_or_default() as _;
 
         Some(Self {
             level_id,
@@ -59,10 +213,39 @@ impl Sonic3 {
             game_mode,
             game_mode_offset,
             hpz_flag,
+            timer_frames,
+            player_mode,
+            emerald_count,
+            blue_spheres_stage,
+            time_bonus,
+            demo_mode,
+            rings,
             level_watcher: Watcher::new(),
+            time_bonus_value: Watcher::new(),
+            time_bonus_start_value: u32::default(),
         })
     }
 
+    pub fn print_addresses(&self) {
+        crate::print_addresses(
+            "Sonic 3 & Knuckles",
+            &[
+                ("level_id", Some(self.level_id)),
+                ("level_id_apparent", Some(self.level_id_apparent)),
+                ("status", Some(self.status)),
+                ("game_mode", Some(self.game_mode)),
+                ("hpz_flag", Some(self.hpz_flag)),
+                ("timer_frames", self.timer_frames),
+                ("player_mode", self.player_mode),
+                ("emerald_count", self.emerald_count),
+                ("blue_spheres_stage", self.blue_spheres_stage),
+                ("time_bonus", self.time_bonus),
+                ("demo_mode", self.demo_mode),
+                ("rings", self.rings),
+            ],
+        );
+    }
+
     pub fn get_current_level(&mut self, process: &Process) -> LevelID {
         let level = self.level_watcher.update_infallible({
             let act = process.read::<u8>(self.level_id).unwrap_or_default();
@@ -139,8 +322,25 @@ impl Sonic3 {
         level.current
     }
 
-    pub fn is_in_time_bonus(&self) -> bool {
-        false
+    pub fn is_in_time_bonus(&mut self, process: &Process) -> bool {
+        let Some(time_bonus) = self.time_bonus else {
+            return false;
+        };
+
+        let time_bonus =
+            self.time_bonus_value
+                .update_infallible(match process.read::<[u16; 2]>(time_bonus) {
+                    Ok([time, rings]) => time as u32 + rings as u32,
+                    _ => 0,
+                });
+
+        if time_bonus.changed_from(&0) {
+            self.time_bonus_start_value = time_bonus.current;
+        } else if time_bonus.current == 0 {
+            self.time_bonus_start_value = 0;
+        }
+
+        self.time_bonus_start_value != 0 && time_bonus.current != self.time_bonus_start_value
     }
 
     pub fn get_start_trigger(&mut self, _process: &Process) -> bool {
@@ -150,8 +350,45 @@ impl Sonic3 {
         })
     }
 
-    pub fn is_demo_mode(&self) -> bool {
-        false
+    pub fn get_stage_time(&self, process: &Process) -> Option<StageTime> {
+        let [minutes, seconds, frames] = process
+            .read::<[u8; 3]>(self.timer_frames?.add_signed(-2))
+            .ok()?;
+        Some(StageTime {
+            minutes,
+            seconds,
+            frames,
+        })
+    }
+
+    pub fn get_character(&self, process: &Process) -> Option<Character> {
+        Some(match process.read::<u16>(self.player_mode?) {
+            Ok(1) => Character::Sonic,
+            Ok(2) => Character::Tails,
+            Ok(3) => Character::Knuckles,
+            _ => Character::SonicAndTails,
+        })
+    }
+
+    pub fn get_chaos_emeralds(&self, process: &Process) -> Option<u8> {
+        process.read(self.emerald_count?).ok()
+    }
+
+    pub fn get_super_emeralds(&self, process: &Process) -> Option<u8> {
+        process.read(self.emerald_count? + 1).ok()
+    }
+
+    pub fn get_blue_spheres_stage(&self, process: &Process) -> Option<u16> {
+        process.read(self.blue_spheres_stage?).ok()
+    }
+
+    pub fn get_rings(&self, process: &Process) -> Option<u16> {
+        process.read(self.rings?).ok()
+    }
+
+    pub fn is_demo_mode(&self, process: &Process) -> bool {
+        self.demo_mode
+            .is_some_and(|addr| process.read::<u16>(addr).is_ok_and(|val| val != 0))
     }
 
     pub fn get_game_mode(&self, process: &Process) -> GameMode {
@@ -167,3 +404,24 @@ impl Sonic3 {
         }
     }
 }
+
+/// Resolves a RIP-relative operand for one of the extras, `operand` bytes into the
+/// match and `next` bytes before the following instruction. The signature has to match
+/// exactly once; a miss is reported but doesn't stop the rest of the splitter.
+fn extra_address<const N: usize>(
+    process: &Process,
+    main_module_range: (Address, u64),
+    signature: &Signature<N>,
+    name: &str,
+    operand: u64,
+    next: u64,
+) -> Option<Address> {
+    let ptr = crate::scan_unique(
+        process,
+        main_module_range,
+        signature,
+        "Sonic 3 & Knuckles",
+        name,
+    )? + operand;
+    Some(ptr + next + process.read::<i32>(ptr).ok()?)
+}
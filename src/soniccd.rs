@@ -1,14 +1,36 @@
+use crate::retroengine::{EngineBuild, Resolver, RetroEngine, StageVariable};
 use crate::{GameMode, LevelID};
-use asr::{signature::Signature, watcher::Watcher, Address, Process};
+use asr::{watcher::Watcher, Address, Process};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TimePeriod {
+    Present,
+    Past,
+    GoodFuture,
+    BadFuture,
+}
+
+impl TimePeriod {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            TimePeriod::Present => "Present",
+            TimePeriod::Past => "Past",
+            TimePeriod::GoodFuture => "Good Future",
+            TimePeriod::BadFuture => "Bad Future",
+        }
+    }
+}
 
 pub struct SonicCD {
+    pub engine: RetroEngine,
     level_id: Address,
     time_bonus: Address,
     start_trigger: Address,
     demo_mode: Address,
     game_mode: Address,
     time_travel: Address,
-    level_watcher: Watcher<LevelID>,
+    time_stones: Option<Address>,
+    good_future_flags: Option<Address>,
     start_trigger_value: Watcher<u8>,
     time_bonus_value: Watcher<u32>,
     time_bonus_start_value: u32,
@@ -16,87 +38,85 @@ pub struct SonicCD {
 
 impl SonicCD {
     pub fn new(process: &Process, main_module_range: (Address, u64)) -> Option<Self> {
-        let ptr = {
-            const PTR: Signature<27> = Signature::new(
-                "3D E4 00 00 00 0F 87 ?? ?? ?? ?? 41 8B 8C 84 ?? ?? ?? ?? 49 03 CC FF ?? 41 8B 84",
-            );
-            let scanned = PTR.scan_process_range(process, main_module_range)? + 15;
-            main_module_range.0 + process.read::<i32>(scanned).ok()?
-        };
-
-        let lea = {
-            const LEA: Signature<8> = Signature::new("EB ?? 45 33 FF 45 85 D2");
-            let scanned = LEA.scan_process_range(process, main_module_range)? + 1;
-            let temp_addr = scanned + 0x4 + process.read::<u8>(scanned).ok()?;
-            temp_addr + 0x4 + process.read::<i32>(temp_addr).ok()?
-        };
-
-        let pointer_path = |offset1, offset2, offset3, absolute| {
-            if offset1 == 0 {
-                lea + offset3
-            } else {
-                let temp_offset = process.read::<i32>(ptr + offset1).unwrap_or_default();
-                let temp_offset_2 = main_module_range.0 + temp_offset + offset2;
-
-                if absolute {
-                    main_module_range.0
-                        + process.read::<i32>(temp_offset_2).unwrap_or_default()
-                        + offset3
-                } else {
-                    temp_offset_2
-                        + 0x4
-                        + process.read::<i32>(temp_offset_2).unwrap_or_default()
-                        + offset3
-                }
-            }
-        };
+        let resolver = Resolver::new(process, main_module_range, "Sonic CD", EngineBuild::SonicCD)?;
 
         Some(Self {
-            level_id: pointer_path(0x4 * 120, 46, 0, false),
-            time_bonus: pointer_path(0, 0, 0x814, false),
-            start_trigger: pointer_path(0, 0, 0x942, false),
-            demo_mode: pointer_path(0x4 * 11, 15, 0x6B * 4, true),
-            game_mode: pointer_path(0x4 * 11, 15, 0x77 * 4, true),
-            time_travel: pointer_path(0x4 * 11, 15, 0x1E * 4, true),
-            level_watcher: Watcher::new(),
+            engine: RetroEngine::new(&resolver),
+            level_id: resolver.stage_variable(StageVariable::ListPos)?,
+            time_bonus: resolver.object_memory("time_bonus", 0x814)?,
+            start_trigger: resolver.object_memory("start_trigger", 0x942)?,
+            demo_mode: resolver.global_or("options.attractMode", 0x6B)?,
+            game_mode: resolver.global_or("options.gameMode", 0x77)?,
+            time_travel: resolver.global_or("timeTravel.active", 0x1E)?,
+            time_stones: resolver.global("specialStage.timeStones"),
+            good_future_flags: resolver.global("timeTravel.goodFutureList"),
             start_trigger_value: Watcher::new(),
             time_bonus_value: Watcher::new(),
             time_bonus_start_value: u32::default(),
         })
     }
 
-    pub fn get_current_level(&mut self, process: &Process) -> LevelID {
+    pub fn print_addresses(&self) {
+        crate::print_addresses(
+            "Sonic CD",
+            &[
+                ("level_id", Some(self.level_id)),
+                ("time_bonus", Some(self.time_bonus)),
+                ("start_trigger", Some(self.start_trigger)),
+                ("demo_mode", Some(self.demo_mode)),
+                ("game_mode", Some(self.game_mode)),
+                ("time_travel", Some(self.time_travel)),
+                ("time_stones", self.time_stones),
+                ("good_future_flags", self.good_future_flags),
+            ],
+        );
+        self.engine.print_addresses("Sonic CD");
+    }
+
+    pub fn get_current_level(&self, process: &Process) -> LevelID {
         match process.read::<u8>(self.level_id).unwrap_or_default() {
             0 => LevelID::SonicCD_TitleScreen,
             8 => LevelID::SonicCD_Ending,
-            13 | 14 | 15 | 16 => LevelID::SonicCD_PalmtreePanicAct1,
-            17 | 18 | 19 | 20 => LevelID::SonicCD_PalmtreePanicAct2,
-            21 | 22 => LevelID::SonicCD_PalmtreePanicAct3,
-            23 | 24 | 25 | 26 => LevelID::SonicCD_CollisionChaosAct1,
-            27 | 28 | 29 | 30 => LevelID::SonicCD_CollisionChaosAct2,
-            31 | 32 => LevelID::SonicCD_CollisionChaosAct3,
-            33 | 34 | 35 | 36 => LevelID::SonicCD_TidalTempestAct1,
-            37 | 38 | 39 | 40 => LevelID::SonicCD_TidalTempestAct2,
-            41 | 42 => LevelID::SonicCD_TidalTempestAct3,
-            43 | 44 | 45 | 46 => LevelID::SonicCD_QuartzQuadrantAct1,
-            47 | 48 | 49 | 50 => LevelID::SonicCD_QuartzQuadrantAct2,
-            51 | 52 => LevelID::SonicCD_QuartzQuadrantAct3,
-            53 | 54 | 55 | 56 => LevelID::SonicCD_WackyWorkbenchAct1,
-            57 | 58 | 59 | 60 => LevelID::SonicCD_WackyWorkbenchAct2,
-            61 | 62 => LevelID::SonicCD_WackyWorkbenchAct3,
-            63 | 64 | 65 | 66 => LevelID::SonicCD_StardustSpeedwayAct1,
-            67 | 68 | 69 | 70 => LevelID::SonicCD_StardustSpeedwayAct2,
-            71 | 72 => LevelID::SonicCD_StardustSpeedwayAct3,
-            73 | 74 | 75 | 76 => LevelID::SonicCD_MetallicMadnessAct1,
-            77 | 78 | 79 | 80 => LevelID::SonicCD_MetallicMadnessAct2,
-            81 | 82 => LevelID::SonicCD_MetallicMadnessAct3,
-            _ => match self.level_watcher.pair {
-                Some(x) => x.current,
-                _ => LevelID::MainMenu,
+            stage => match decode_stage(stage) {
+                Some((round, act, _)) => ROUNDS[round][act],
+                _ => LevelID::Unknown,
             },
         }
     }
 
+    pub fn get_time_period(&self, process: &Process) -> TimePeriod {
+        match decode_stage(process.read(self.level_id).unwrap_or_default()) {
+            Some((_, _, time_period)) => time_period,
+            _ => TimePeriod::Present,
+        }
+    }
+
+    pub fn get_time_stones(&self, process: &Process) -> Option<u8> {
+        process
+            .read::<u8>(self.time_stones?)
+            .ok()
+            .map(|val| val.count_ones() as u8)
+    }
+
+    /// Act 3 is in a Good Future when it is loaded as such. Acts 1 and 2 count
+    /// as soon as the machine hidden in the Past has been destroyed, which is
+    /// unknown if the flags couldn't be found.
+    pub fn is_good_future(&self, process: &Process) -> Option<bool> {
+        let Some((round, act, time_period)) =
+            decode_stage(process.read(self.level_id).unwrap_or_default())
+        else {
+            return Some(false);
+        };
+
+        Some(
+            time_period == TimePeriod::GoodFuture
+                || (act < 2
+                    && process
+                        .read::<u32>(self.good_future_flags?)
+                        .is_ok_and(|flags| flags & (1 << (round * 2 + act)) != 0)),
+        )
+    }
+
     pub fn is_in_time_bonus(&mut self, process: &Process) -> bool {
         let time_bonus = self
             .time_bonus_value
@@ -108,6 +128,7 @@ impl SonicCD {
             self.time_bonus_start_value = 0;
         }
 
+        // The time travel flag is only set while a warp to another time period plays out
         (self.time_bonus_start_value != 0 && time_bonus.current != self.time_bonus_start_value)
             || process.read::<u32>(self.time_travel).unwrap_or_default() != 0
     }
@@ -136,3 +157,66 @@ impl SonicCD {
         }
     }
 }
+
+const ROUNDS: [[LevelID; 3]; 7] = [
+    [
+        LevelID::SonicCD_PalmtreePanicAct1,
+        LevelID::SonicCD_PalmtreePanicAct2,
+        LevelID::SonicCD_PalmtreePanicAct3,
+    ],
+    [
+        LevelID::SonicCD_CollisionChaosAct1,
+        LevelID::SonicCD_CollisionChaosAct2,
+        LevelID::SonicCD_CollisionChaosAct3,
+    ],
+    [
+        LevelID::SonicCD_TidalTempestAct1,
+        LevelID::SonicCD_TidalTempestAct2,
+        LevelID::SonicCD_TidalTempestAct3,
+    ],
+    [
+        LevelID::SonicCD_QuartzQuadrantAct1,
+        LevelID::SonicCD_QuartzQuadrantAct2,
+        LevelID::SonicCD_QuartzQuadrantAct3,
+    ],
+    [
+        LevelID::SonicCD_WackyWorkbenchAct1,
+        LevelID::SonicCD_WackyWorkbenchAct2,
+        LevelID::SonicCD_WackyWorkbenchAct3,
+    ],
+    [
+        LevelID::SonicCD_StardustSpeedwayAct1,
+        LevelID::SonicCD_StardustSpeedwayAct2,
+        LevelID::SonicCD_StardustSpeedwayAct3,
+    ],
+    [
+        LevelID::SonicCD_MetallicMadnessAct1,
+        LevelID::SonicCD_MetallicMadnessAct2,
+        LevelID::SonicCD_MetallicMadnessAct3,
+    ],
+];
+
+/// Each round takes up ten entries of the stage list, starting from 13: Present, Past,
+/// Good Future and Bad Future for acts 1 and 2, then the Good and Bad Future of act 3.
+fn decode_stage(stage: u8) -> Option<(usize, usize, TimePeriod)> {
+    let offset = stage.checked_sub(13)? as usize;
+    let round = offset / 10;
+    if round >= ROUNDS.len() {
+        return None;
+    }
+
+    Some(match offset % 10 {
+        8 => (round, 2, TimePeriod::GoodFuture),
+        9 => (round, 2, TimePeriod::BadFuture),
+        val => (
+            round,
+            val / 4,
+            match val % 4 {
+                0 => TimePeriod::Present,
+                1 => TimePeriod::Past,
+                2 => TimePeriod::GoodFuture,
+                _ => TimePeriod::BadFuture,
+            },
+        ),
+    })
+}
@@ -0,0 +1,19 @@
+use asr::{file_format::pe, Address, Process};
+
+/// PE header fields logged on attach, to identify the build of the executable.
+pub struct ExecutableInfo {
+    pub timestamp: u32,
+    pub size_of_image: u32,
+}
+
+impl ExecutableInfo {
+    pub fn read(process: &Process, main_module_base: Address) -> Option<Self> {
+        // e_lfanew points at the PE signature, which is followed by the COFF header
+        let pe_header = main_module_base + process.read::<u32>(main_module_base + 0x3C).ok()?;
+
+        Some(Self {
+            timestamp: process.read(pe_header + 0x8).ok()?,
+            size_of_image: pe::read_size_of_image(process, main_module_base)?,
+        })
+    }
+}